@@ -37,6 +37,23 @@ fn criterion_benchmark(c: &mut Criterion)
     c.bench_function("merge_500000", |b| b.iter(|| merge_fronts(front1.clone(), front2.clone())));
 }
 
+/// merges many fronts, as one would get from a large number of threads
+fn many_fronts_benchmark(c: &mut Criterion)
+{
+    // data used for the push
+    let seed = 42;
+    let data = ParetoElement::sample_n(500000, seed);
+    // one front per chunk of data
+    let nb_fronts = 64;
+    let fronts: Vec<ParetoFront<_>> =
+        data.chunks(data.len() / nb_fronts).map(|chunk| chunk.iter().cloned().collect()).collect();
+    // compares merging the fronts one after the other with merging them as a tree
+    let mut group = c.benchmark_group("merge_64_fronts");
+    group.bench_function("sequential", |b| b.iter(|| fronts.iter().cloned().reduce(merge_fronts)));
+    group.bench_function("merge_all", |b| b.iter(|| ParetoFront::merge_all(fronts.iter().cloned())));
+    group.finish();
+}
+
 // compares two implementation of the `push` function
 /*fn comparison_benchmark(c: &mut Criterion)
 {
@@ -54,5 +71,5 @@ fn criterion_benchmark(c: &mut Criterion)
     group.finish();
 }*/
 
-criterion_group!(benches, criterion_benchmark);
+criterion_group!(benches, criterion_benchmark, many_fronts_benchmark);
 criterion_main!(benches);
@@ -1,6 +1,8 @@
 //! Element types that used to run the `ParetoFront` benchmarks
-#![allow(dead_code)]
+#![allow(dead_code, unused_imports)]
 mod random;
+pub use random::ParetoElementRandom;
 mod circle;
 pub use circle::ParetoElementCircle;
 mod circle5d;
+pub use circle5d::ParetoElementCircle5D;
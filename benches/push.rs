@@ -4,7 +4,7 @@ use criterion::{criterion_group, criterion_main, Criterion};
 mod pareto_element;
 use pareto_element::ParetoElementCircle as ParetoElement;
 // pareto front
-use pareto_front::ParetoFront;
+use pareto_front::{OrderedParetoFront, ParetoFront};
 
 /// inserts all the element from data into a pareto front using the `push` function
 /// we use push explicitely (instead of `collect`) to make it easy to test alternative push implementations
@@ -18,6 +18,17 @@ fn generate_front(data: &[ParetoElement]) -> ParetoFront<ParetoElement>
     front
 }
 
+/// inserts all the element from data into an ordered pareto front using the `push` function
+fn generate_ordered_front(data: &[ParetoElement]) -> OrderedParetoFront<ParetoElement>
+{
+    let mut front = OrderedParetoFront::new();
+    for x in data
+    {
+        front.push(*x);
+    }
+    front
+}
+
 /*fn generate_front2(data: &[ParetoElement]) -> ParetoFront<ParetoElement>
 {
     let mut front = ParetoFront::new();
@@ -41,6 +52,19 @@ fn criterion_benchmark(c: &mut Criterion)
     c.bench_function("insert 5000", |b| b.iter(|| generate_front(&data)));
 }
 
+/// measures the cost of preserving insertion order
+fn ordered_benchmark(c: &mut Criterion)
+{
+    // data used for the bench
+    let seed = 42;
+    let data = ParetoElement::sample_n(5000, seed);
+    // compares both fronts
+    let mut group = c.benchmark_group("ordered_insert_5000");
+    group.bench_function("ParetoFront", |b| b.iter(|| generate_front(&data)));
+    group.bench_function("OrderedParetoFront", |b| b.iter(|| generate_ordered_front(&data)));
+    group.finish();
+}
+
 /*
 /// compares two implementation of the `push` function
 fn comparison_benchmark(c: &mut Criterion)
@@ -55,5 +79,5 @@ fn comparison_benchmark(c: &mut Criterion)
     group.finish();
 } */
 
-criterion_group!(benches, criterion_benchmark);
+criterion_group!(benches, criterion_benchmark, ordered_benchmark);
 criterion_main!(benches);
@@ -1,15 +1,19 @@
 // benchmarking lib
 use criterion::{criterion_group, criterion_main, Criterion};
+#[cfg(feature = "pareto_front_rayon")]
+use criterion::BatchSize;
 // element type to do our benchmarks on
 mod pareto_element;
 use pareto_element::ParetoElementCircle as ParetoElement;
+#[cfg(feature = "pareto_front_rayon")]
+use pareto_element::ParetoElementCircle5D;
 // pareto front
 use pareto_front::ParetoFront;
 // paralelism
-#[cfg(feature = "pareto_front_concurrent")]
+#[cfg(any(feature = "pareto_front_concurrent", feature = "pareto_front_rayon"))]
 use rayon::prelude::*;
 #[cfg(feature = "pareto_front_concurrent")]
-use pareto_front::ConcurrentParetoFront;
+use pareto_front::{ConcurrentParetoFront, SharedParetoFront};
 
 /// inserts all the element from data into a pareto front using the `push` function
 fn generate_front(data: &[ParetoElement]) -> ParetoFront<ParetoElement>
@@ -32,6 +36,17 @@ fn generate_front_concurrent(data: &[ParetoElement]) -> ParetoFront<ParetoElemen
     concurrent_front.into_sequential()
 }
 
+/// insert concurrently into a single front protected by a lock
+#[cfg(feature = "pareto_front_concurrent")]
+fn generate_front_shared(data: &[ParetoElement]) -> ParetoFront<ParetoElement>
+{
+    let shared_front = SharedParetoFront::new();
+    data.par_iter().for_each(|x| {
+                       shared_front.push(*x);
+                   });
+    shared_front.into_sequential()
+}
+
 /// same thing but without `into_sequential` to evaluate its cost
 #[cfg(feature = "pareto_front_concurrent")]
 fn generate_front_concurrent_unreduced(data: &[ParetoElement]) -> ConcurrentParetoFront<ParetoElement>
@@ -43,6 +58,13 @@ fn generate_front_concurrent_unreduced(data: &[ParetoElement]) -> ConcurrentPare
     concurrent_front
 }
 
+/// collects a parallel iterator into a pareto front
+#[cfg(feature = "pareto_front_rayon")]
+fn generate_front_par_collect(data: &[ParetoElement]) -> ParetoFront<ParetoElement>
+{
+    data.par_iter().cloned().collect()
+}
+
 /// compares two implementation of the `push` function
 fn comparison_benchmark(c: &mut Criterion)
 {
@@ -58,8 +80,48 @@ fn comparison_benchmark(c: &mut Criterion)
     group.bench_function("push_concurrent_unreduced", |b| {
              b.iter(|| generate_front_concurrent_unreduced(&data))
          });
+    #[cfg(feature = "pareto_front_concurrent")]
+    group.bench_function("push_shared", |b| b.iter(|| generate_front_shared(&data)));
+    #[cfg(feature = "pareto_front_rayon")]
+    group.bench_function("par_collect", |b| b.iter(|| generate_front_par_collect(&data)));
+    group.finish();
+}
+
+/// compares `push` and `par_push` on a large 5D front
+#[cfg(feature = "pareto_front_rayon")]
+fn par_push_benchmark(c: &mut Criterion)
+{
+    // a large front (points on a sphere do not dominate each other) and candidates to push into it
+    let seed = 42;
+    let data = ParetoElementCircle5D::sample_n(100000, seed);
+    let (front_data, candidates) = data.split_at(data.len() - 100);
+    let front: ParetoFront<_> = front_data.par_iter().cloned().collect();
+    println!("Front size: {}", front.len());
+    // compares both functions
+    let mut group = c.benchmark_group("par_push_circle5d_100000");
+    group.bench_function("push", |b| {
+             b.iter_batched(|| front.clone(),
+                            |mut front| {
+                                candidates.iter().for_each(|x| {
+                                                     front.push(*x);
+                                                 })
+                            },
+                            BatchSize::LargeInput)
+         });
+    group.bench_function("par_push", |b| {
+             b.iter_batched(|| front.clone(),
+                            |mut front| {
+                                candidates.iter().for_each(|x| {
+                                                     front.par_push(*x);
+                                                 })
+                            },
+                            BatchSize::LargeInput)
+         });
     group.finish();
 }
 
+#[cfg(not(feature = "pareto_front_rayon"))]
 criterion_group!(benches, comparison_benchmark);
+#[cfg(feature = "pareto_front_rayon")]
+criterion_group!(benches, comparison_benchmark, par_push_benchmark);
 criterion_main!(benches);
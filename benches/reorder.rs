@@ -0,0 +1,50 @@
+// benchmarking lib
+use criterion::{criterion_group, criterion_main, Criterion};
+// element types to do our benchmarks on
+mod pareto_element;
+use pareto_element::{ParetoElementCircle, ParetoElementCircle5D, ParetoElementRandom};
+// pareto front
+use pareto_front::{Dominate, ParetoFront, ReorderStrategy};
+
+/// all the strategies to be compared
+const STRATEGIES: [ReorderStrategy; 4] = [ReorderStrategy::None,
+                                          ReorderStrategy::SwapWithPrevious,
+                                          ReorderStrategy::MoveToFront,
+                                          ReorderStrategy::CountBased];
+
+/// inserts all the element from data into a pareto front using the given reorder strategy
+fn generate_front<T: Dominate + Copy>(data: &[T], reorder_strategy: ReorderStrategy) -> ParetoFront<T>
+{
+    let mut front = ParetoFront::new().with_reorder_strategy(reorder_strategy);
+    for x in data
+    {
+        front.push(*x);
+    }
+    front
+}
+
+/// compares all reorder strategies on the given data
+fn compare_strategies<T: Dominate + Copy>(c: &mut Criterion, name: &str, data: &[T])
+{
+    let mut group = c.benchmark_group(name);
+    for reorder_strategy in STRATEGIES
+    {
+        group.bench_function(format!("{:?}", reorder_strategy), |b| {
+                 b.iter(|| generate_front(data, reorder_strategy))
+             });
+    }
+    group.finish();
+}
+
+/// measures the speed of several insertions in a row, for each strategy and distribution
+fn criterion_benchmark(c: &mut Criterion)
+{
+    // data used for the bench
+    let seed = 42;
+    compare_strategies(c, "reorder_random_5000", &ParetoElementRandom::sample_n(5000, seed));
+    compare_strategies(c, "reorder_circle_5000", &ParetoElementCircle::sample_n(5000, seed));
+    compare_strategies(c, "reorder_circle5d_5000", &ParetoElementCircle5D::sample_n(5000, seed));
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -10,9 +10,37 @@
 //!
 //! This crate gives you access to the `ParetoFront` type which can be created (empty or from an iterator), updated by adding new potential elements (using the `push` or the `extend` method) and converted into an iterator, a slice or a vector.
 //!
-//! The `pareto_front_concurrent` feature unlocks the `ConcurrentParetoFront` type which can be used inside a parallel algorithm without needing to put a lock around a `ParetoFront`.
+//! The `OrderedParetoFront` type can be used instead of `ParetoFront` when the elements should be kept in insertion order (at a small performance cost). Conversely, the way `ParetoFront` reorders its elements to speed up insertions can be chosen with `with_reorder_strategy`.
 //!
-//! The `pareto_front_serde` feature lets you serialize and deserialize the `ParetoFront` type using [serde](https://serde.rs/).
+//! Each element gets an `ElementId` when it enters the front (returned by `push_with_id`), it stays valid across mutations of the front and can be used to `get` or `remove` the element. Calling `checkpoint` then `changes_since` gives you a `FrontDiff` listing the elements that entered and left the front in the meantime (useful to update a display or a database incrementally).
+//!
+//! A `FrontObserver` can be attached to a front (using `with_observer`) to be notified of every element accepted, rejected or evicted by `push`, `merge` and `extend`, fronts without an observer pay no overhead.
+//!
+//! Many fronts (one per thread for example) can be merged at once with `ParetoFront::merge_all`, which merges them pairwise as a tree. Fronts can be compared as sets (equality, `union` and `is_subset`) and the `metrics` module provides the coverage metric as well as a `compare` function telling you whether a front is better, worse, equal or incomparable to another one.
+//!
+//! The dominance relation used by a front can be replaced with a comparator implementing the `Dominance` trait (using `ParetoFront::with_comparator`), the `relaxed_dominance` module provides relations (k-dominance, α-dominance, grid-dominance and fuzzy-dominance) that keep the front meaningful when dealing with a large number of objectives.
+//!
+//! Floating point objectives that might be NaN can be handled with the `nan_safe` module, which treats NaN as the worst possible value, and the `ParetoFront::try_push` method, which refuses elements with NaN objectives depending on their `NanPolicy`.
+//!
+//! Elements implementing the `Objectives` trait can be pushed into a `ReferencePointFront`, which only keeps the elements of the front that are close enough to a given reference point, in order to focus the front on a region of interest.
+//!
+//! The `pareto_front_concurrent` feature unlocks the `ConcurrentParetoFront` type which can be used inside a parallel algorithm without needing to put a lock around a `ParetoFront`. It can be inspected while it is being filled (using `snapshot` and `dominate`) and compacted, using `compact`, to drop the elements of a thread that are dominated by the elements of other threads and to share the best elements of each thread with all threads, so that later pushes are rejected early. It also unlocks the `SharedParetoFront` type, a single front protected by a read-write lock, whose `push` gives an answer that holds across all threads (at the cost of some contention). Finally, the `ParetoFrontActor` type owns a front on a dedicated thread and is fed through a bounded channel, its cloneable `ParetoFrontHandle` can be used from any thread or async task.
+//!
+//! The `pareto_front_blanket` feature implements `Dominate` for arrays, slices, vectors and tuples (minimizing all axes, axes wrapped in `Maximize` being maximized) as well as for pointers wrapped in `Forward` (forwarding to the type they point to).
+//!
+//! The `pareto_front_rayon` feature integrates `ParetoFront` with [rayon](https://github.com/rayon-rs/rayon): a parallel iterator can be collected into a front (`FromParallelIterator` and `ParallelExtend`), a front can be iterated in parallel (`par_iter`), elements can be pushed into very large fronts by splitting the scan between threads (`par_push`) and fronts can be merged in parallel (`ParetoFront::par_merge_all` and `ConcurrentParetoFront::into_sequential`).
+//!
+//! The `pareto_front_stats` feature makes `ParetoFront` and `ConcurrentParetoFront` count the dominance comparisons, early rejections, evictions and merges they perform (see `FrontStats`), which is useful to tune a `Dominate` implementation. The counters are compiled out entirely when the feature is disabled.
+//!
+//! The `pareto_front_csv` feature lets you read a front from a CSV file (`ParetoFront::from_csv_reader`, which pushes the rows as they are read and can select the columns holding the objectives) and write it back (`ParetoFront::write_csv`), elements being converted with serde.
+//!
+//! The `pareto_front_journal` feature provides `JournalObserver`, an observer appending every element accepted by a front to a JSON Lines journal (synced to disk after each line with `JournalObserver::with_sync`, to also survive power losses), the front can be rebuilt from the journal after a crash (`ParetoFront::recover_journal`, which also drops a line interrupted by the crash) and the journal compacted down to the current front (`ParetoFront::compact_journal`).
+//!
+//! The `pareto_front_binary` feature lets you save a front to a file (`ParetoFront::save`) and load it back (`ParetoFront::load`) using a compact, versioned, binary format meant for fronts of millions of elements, elements need to implement `Objectives` and are encoded with serde. Loading fails if an element of the file is dominated by another one, `ParetoFront::load_repair` can be used instead to drop such elements.
+//!
+//! The `pareto_front_cli` feature builds the `pareto` command-line tool, which reads CSV, TSV or JSON Lines tables from files or the standard input and writes their non-dominated rows (with all their columns) to the standard output, objectives being given with `--min` and `--max`.
+//!
+//! The `pareto_front_serde` feature lets you serialize and deserialize the `ParetoFront` and `ConcurrentParetoFront` types using [serde](https://serde.rs/). Deserialization fails if an element of the front is dominated by another one, `ParetoFront::deserialize_repair` can be used instead to drop such elements.
 //!
 //! ## Usage
 //!
@@ -81,6 +109,31 @@
 
 mod pareto_front_implementation;
 pub use self::pareto_front_implementation::Dominate;
+pub use self::pareto_front_implementation::Objectives;
+pub use self::pareto_front_implementation::nan_safe;
+pub use self::pareto_front_implementation::{NanError, NanPolicy};
+pub use self::pareto_front_implementation::{Dominance, NaturalDominance};
+#[cfg(feature = "pareto_front_blanket")]
+pub use self::pareto_front_implementation::{Forward, Maximize};
 pub use self::pareto_front_implementation::ParetoFront;
+pub use self::pareto_front_implementation::OrderedParetoFront;
+pub use self::pareto_front_implementation::ReorderStrategy;
+pub use self::pareto_front_implementation::{Checkpoint, ElementId, FrontDiff};
+pub use self::pareto_front_implementation::{FrontObserver, NoObserver};
+#[cfg(feature = "pareto_front_stats")]
+pub use self::pareto_front_implementation::FrontStats;
 #[cfg(feature = "pareto_front_concurrent")]
 pub use self::pareto_front_implementation::ConcurrentParetoFront;
+#[cfg(feature = "pareto_front_concurrent")]
+pub use self::pareto_front_implementation::SharedParetoFront;
+#[cfg(feature = "pareto_front_concurrent")]
+pub use self::pareto_front_implementation::{ParetoFrontActor, ParetoFrontHandle};
+#[cfg(feature = "pareto_front_csv")]
+pub use self::pareto_front_implementation::{CsvColumn, CsvError, CsvErrorKind, CsvOptions};
+#[cfg(feature = "pareto_front_journal")]
+pub use self::pareto_front_implementation::{JournalError, JournalErrorKind, JournalObserver};
+#[cfg(feature = "pareto_front_binary")]
+pub use self::pareto_front_implementation::BinaryError;
+pub use self::pareto_front_implementation::{ReferencePoint, ReferencePointFront};
+pub use self::pareto_front_implementation::relaxed_dominance;
+pub use self::pareto_front_implementation::metrics;
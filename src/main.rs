@@ -1,40 +1,378 @@
-#![allow(dead_code)]
-use pareto_front::{Dominate, ParetoFront};
+//! `pareto`: keeps the non-dominated rows of a table.
+//!
+//! Reads CSV, TSV or JSON Lines tables from files (or the standard input),
+//! pushes their rows into a `ParetoFront` using the given objective columns
+//! and writes the rows that are in the front, in their original order and with all their columns, to the standard output.
+use pareto_front::nan_safe::nan_safe_dominate;
+use pareto_front::{CsvColumn, Dominate, NanPolicy, Objectives, ParetoFront};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::{env, fs, process};
 
-/// test element
-#[derive(Debug, PartialEq)]
-struct ParetoElement
+const USAGE: &str = "\
+Usage: pareto [OPTIONS] (--min COLUMN | --max COLUMN)... [FILE]...
+
+Writes the non-dominated rows of the input tables to the standard output,
+in their original order and with all their columns.
+Reads the standard input if no file (or `-`) is given.
+
+Options:
+    --min COLUMN       objective to be minimized (can be repeated)
+    --max COLUMN       objective to be maximized (can be repeated)
+    --format FORMAT    csv, tsv or jsonl (deduced from the extension of the first file, csv by default)
+    --no-header        CSV and TSV tables have no header, columns are given by index (starting at 1)
+    -h, --help         prints this message
+
+Rows whose objectives are all equal do not dominate each other and are all kept.
+NaN objectives are considered worse than any other value.";
+
+/// Direction in which an objective is optimized.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Direction
+{
+    Min,
+    Max
+}
+
+/// Format of the tables read and written.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Format
+{
+    Csv,
+    Tsv,
+    JsonLines
+}
+
+impl Format
+{
+    /// Parses the value of the `--format` option.
+    fn parse(format: &str) -> Result<Self, String>
+    {
+        match format
+        {
+            "csv" => Ok(Format::Csv),
+            "tsv" => Ok(Format::Tsv),
+            "jsonl" | "ndjson" => Ok(Format::JsonLines),
+            _ => Err(format!("unknown format '{format}' (expected csv, tsv or jsonl)"))
+        }
+    }
+
+    /// Deduces the format from the extension of `path`, defaulting to CSV.
+    fn from_path(path: &str) -> Self
+    {
+        match Path::new(path).extension().and_then(|extension| extension.to_str())
+        {
+            Some("tsv" | "tab") => Format::Tsv,
+            Some("jsonl" | "ndjson") => Format::JsonLines,
+            _ => Format::Csv
+        }
+    }
+}
+
+/// Parsed command-line arguments.
+#[derive(Debug)]
+struct Arguments
+{
+    objectives: Vec<(String, Direction)>,
+    format: Format,
+    has_headers: bool,
+    inputs: Vec<String>
+}
+
+impl Arguments
+{
+    /// Parses the command-line arguments (without the name of the program).
+    /// Returns `None` if help was requested.
+    fn parse<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Self>, String>
+    {
+        let mut objectives = Vec::new();
+        let mut format = None;
+        let mut has_headers = true;
+        let mut inputs = Vec::new();
+        while let Some(arg) = args.next()
+        {
+            let mut value =
+                |option: &str| args.next().ok_or_else(|| format!("missing value after '{option}'"));
+            match arg.as_str()
+            {
+                "--min" => objectives.push((value("--min")?, Direction::Min)),
+                "--max" => objectives.push((value("--max")?, Direction::Max)),
+                "--format" => format = Some(Format::parse(&value("--format")?)?),
+                "--no-header" => has_headers = false,
+                "-h" | "--help" => return Ok(None),
+                "-" => inputs.push(arg),
+                _ if arg.starts_with('-') => return Err(format!("unknown option '{arg}'")),
+                _ => inputs.push(arg)
+            }
+        }
+        if objectives.is_empty()
+        {
+            return Err("at least one objective is needed (using --min or --max)".to_string());
+        }
+        let format =
+            format.unwrap_or_else(|| inputs.first().map_or(Format::Csv, |path| Format::from_path(path)));
+        if inputs.is_empty()
+        {
+            inputs.push("-".to_string());
+        }
+        Ok(Some(Arguments { objectives, format, has_headers, inputs }))
+    }
+}
+
+/// Row of a table, along with its objectives.
+struct Row<C>
 {
-    cost: usize,
-    quality: f32,
-    score: i64
+    /// position of the row in the input, used to output rows in their original order
+    index: usize,
+    /// objectives of the row, negated when they are to be maximized so that they are all to be minimized
+    objectives: Vec<f64>,
+    /// the row, as it will be written to the output
+    content: C
+}
+
+impl<C> Objectives for Row<C>
+{
+    const NAN_POLICY: NanPolicy = NanPolicy::Worst;
+
+    fn nb_objectives(&self) -> usize
+    {
+        self.objectives.len()
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self.objectives[index]
+    }
 }
 
-/// implement the `Dominate` trait
-impl Dominate for ParetoElement
+impl<C> Dominate for Row<C>
 {
     fn dominate(&self, x: &Self) -> bool
     {
-        (self.cost <= x.cost) && (self.quality >= x.quality) && (self.score >= x.score) && (self != x)
+        nan_safe_dominate(self, x)
     }
 }
 
-fn main()
+/// Converts the value of an objective into a number to be minimized.
+fn orient(objective: f64, direction: Direction) -> f64
 {
-    // data to be put in the front
-    let x = ParetoElement { cost: 35, quality: 0.5, score: 4 };
-    let y = ParetoElement { cost: 350, quality: 0.05, score: 2 };
-    let z = ParetoElement { cost: 5, quality: 0.25, score: 5 };
+    match direction
+    {
+        Direction::Min => objective,
+        Direction::Max => -objective
+    }
+}
 
-    // insertions in the front
+/// Parses the value of an objective into a number to be minimized.
+fn parse_objective(value: &str, direction: Direction) -> Option<f64>
+{
+    value.trim().parse().ok().map(|objective| orient(objective, direction))
+}
+
+/// Converts an error that happened while writing the output into a message.
+///
+/// The output being closed early (by `head` for example) is not an error, the program stops quietly.
+fn output_error(error: io::Error) -> String
+{
+    if error.kind() == io::ErrorKind::BrokenPipe
+    {
+        process::exit(0);
+    }
+    error.to_string()
+}
+
+/// Converts an error that happened while writing CSV output into a message, see `output_error`.
+fn csv_output_error(error: csv::Error) -> String
+{
+    match error.kind()
+    {
+        csv::ErrorKind::Io(io_error) if io_error.kind() == io::ErrorKind::BrokenPipe => process::exit(0),
+        _ => error.to_string()
+    }
+}
+
+/// Opens an input, `-` being the standard input.
+fn open(input: &str) -> Result<Box<dyn Read>, String>
+{
+    if input == "-"
+    {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    let file = fs::File::open(input).map_err(|error| format!("{input}: {error}"))?;
+    Ok(Box::new(BufReader::new(file)))
+}
+
+/// Returns the rows of `front` in the order in which they were read.
+fn into_sorted_rows<C>(front: ParetoFront<Row<C>>) -> Vec<Row<C>>
+{
+    let mut rows: Vec<_> = front.into();
+    rows.sort_unstable_by_key(|row| row.index);
+    rows
+}
+
+/// Filters CSV (or TSV) tables.
+fn run_csv<W: Write>(arguments: &Arguments, delimiter: u8, output: W) -> Result<(), String>
+{
+    let mut headers: Option<csv::StringRecord> = None;
+    let mut columns = Vec::new();
     let mut front = ParetoFront::new();
-    front.push(x);
-    front.push(y);
-    front.push(z);
+    let mut index = 0;
+    for (input_index, input) in arguments.inputs.iter().enumerate()
+    {
+        let mut reader = csv::ReaderBuilder::new().has_headers(arguments.has_headers)
+                                                  .delimiter(delimiter)
+                                                  .from_reader(open(input)?);
+        let file_headers = if arguments.has_headers
+        {
+            Some(reader.headers().map_err(|error| format!("{input}: {error}"))?.clone())
+        }
+        else
+        {
+            None
+        };
+
+        // columns are found using the headers of the first file, other files are expected to have the same headers
+        if input_index == 0
+        {
+            let header_names: Option<Vec<&str>> =
+                file_headers.as_ref().map(|headers| headers.iter().collect());
+            columns = arguments.objectives
+                               .iter()
+                               .map(|(column, direction)| {
+                                   Ok((column_index(column, header_names.as_deref())?, *direction))
+                               })
+                               .collect::<Result<Vec<_>, String>>()?;
+            headers = file_headers;
+        }
+        else if file_headers != headers
+        {
+            return Err(format!("{input}: headers differ from the ones of the first file"));
+        }
 
-    // display of the result
-    for (i, element) in front.iter().enumerate()
+        for record in reader.records()
+        {
+            let record = record.map_err(|error| format!("{input}: {error}"))?;
+            let line = record.position().map_or(0, |position| position.line());
+            let objectives = columns.iter()
+                                    .map(|&(column, direction)| {
+                                        let value = record.get(column).ok_or_else(|| {
+                                                                          format!("{input}:{line}: missing column {}", column + 1)
+                                                                      })?;
+                                        parse_objective(value, direction).ok_or_else(|| {
+                                                                             format!("{input}:{line}: '{value}' is not a number")
+                                                                         })
+                                    })
+                                    .collect::<Result<Vec<_>, String>>()?;
+            front.push(Row { index, objectives, content: record });
+            index += 1;
+        }
+    }
+
+    // writes the rows of the front in the format of the input
+    let mut writer = csv::WriterBuilder::new().delimiter(delimiter).from_writer(output);
+    let headers = headers.iter();
+    let rows = into_sorted_rows(front);
+    for record in headers.chain(rows.iter().map(|row| &row.content))
+    {
+        writer.write_record(record).map_err(csv_output_error)?;
+    }
+    writer.flush().map_err(output_error)
+}
+
+/// Returns the index of `column` which is either a header or, when there are no headers, an index starting at 1.
+///
+/// The lookup itself is the one of the library (`CsvColumn::find`), whose indices start at 0.
+fn column_index(column: &str, headers: Option<&[&str]>) -> Result<usize, String>
+{
+    let csv_column = match headers
+    {
+        Some(_) => CsvColumn::Name(column.to_string()),
+        None => match column.parse::<usize>()
+        {
+            Ok(index) if index > 0 => CsvColumn::Index(index - 1),
+            _ =>
+            {
+                return Err(format!("without headers, columns are given by index (starting at 1), not '{column}'"));
+            }
+        }
+    };
+    csv_column.find(headers).ok_or_else(|| format!("unknown column '{column}'"))
+}
+
+/// Filters JSON Lines tables, each line being an object whose fields contain the objectives.
+fn run_json_lines<W: Write>(arguments: &Arguments, mut output: W) -> Result<(), String>
+{
+    let mut front = ParetoFront::new();
+    let mut index = 0;
+    for input in &arguments.inputs
+    {
+        let reader = BufReader::new(open(input)?);
+        for (line_number, line) in reader.lines().enumerate()
+        {
+            let line_number = line_number + 1;
+            let line = line.map_err(|error| format!("{input}:{line_number}: {error}"))?;
+            if line.trim().is_empty()
+            {
+                continue;
+            }
+            let value: serde_json::Value =
+                serde_json::from_str(&line).map_err(|error| format!("{input}:{line_number}: {error}"))?;
+            let objectives = arguments.objectives
+                                      .iter()
+                                      .map(|(field, direction)| {
+                                          let objective = match value.get(field)
+                                          {
+                                              Some(serde_json::Value::Number(number)) => {
+                                                  number.as_f64().map(|objective| orient(objective, *direction))
+                                              }
+                                              Some(serde_json::Value::String(string)) => parse_objective(string, *direction),
+                                              _ => None
+                                          };
+                                          objective.ok_or_else(|| {
+                                                       format!("{input}:{line_number}: field '{field}' is missing or not a number")
+                                                   })
+                                      })
+                                      .collect::<Result<Vec<_>, String>>()?;
+            front.push(Row { index, objectives, content: line });
+            index += 1;
+        }
+    }
+
+    // writes the lines of the front unchanged
+    for row in into_sorted_rows(front)
+    {
+        writeln!(output, "{}", row.content).map_err(output_error)?;
+    }
+    output.flush().map_err(output_error)
+}
+
+fn main()
+{
+    let arguments = match Arguments::parse(env::args().skip(1))
+    {
+        Ok(Some(arguments)) => arguments,
+        Ok(None) =>
+        {
+            println!("{USAGE}");
+            return;
+        }
+        Err(error) =>
+        {
+            eprintln!("pareto: {error}\n\n{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    let output = BufWriter::new(io::stdout().lock());
+    let result = match arguments.format
+    {
+        Format::Csv => run_csv(&arguments, b',', output),
+        Format::Tsv => run_csv(&arguments, b'\t', output),
+        Format::JsonLines => run_json_lines(&arguments, output)
+    };
+    if let Err(error) = result
     {
-        println!("{}: {:?}", i, element);
+        eprintln!("pareto: {error}");
+        process::exit(1);
     }
 }
@@ -0,0 +1,330 @@
+use crate::{Dominance, FrontObserver, Objectives, ParetoFront};
+use bincode::Options;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::fmt;
+
+/// First bytes of all files using the binary format.
+const MAGIC: &[u8; 8] = b"PARETOFR";
+
+/// Major version of the binary format, files with a different major version cannot be read.
+const MAJOR_VERSION: u16 = 1;
+
+/// Minor version of the binary format, minor versions only add fields at the end of the header.
+const MINOR_VERSION: u16 = 0;
+
+/// Number of bytes in the header of a version 1.0 file, after the header length.
+const HEADER_LENGTH: u32 = 4 + 8;
+
+/// Upper bound on the number of elements allocated before reading them, so that a corrupted count cannot exhaust memory.
+const MAX_PREALLOCATED_ELEMENTS: u64 = 1 << 16;
+
+/// Encoding used for the elements: bincode's variable-length integers, in little endian.
+fn element_encoding() -> impl Options + Copy
+{
+    bincode::DefaultOptions::new().with_little_endian().with_varint_encoding()
+}
+
+/// Error encountered while saving or loading a front in the binary format.
+///
+/// **NOTE: This enum is only available when enabling the `pareto_front_binary` feature.**
+#[derive(Debug)]
+pub enum BinaryError
+{
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The file does not start with the magic bytes of the format.
+    NotAFront,
+    /// The file uses a major version of the format that this version of the crate cannot read.
+    UnsupportedVersion
+    {
+        /// major version of the file
+        major: u16,
+        /// minor version of the file
+        minor: u16
+    },
+    /// The elements have more objectives than can be stored in the header.
+    TooManyObjectives(usize),
+    /// An element does not have the number of objectives announced in the header.
+    ObjectiveCountMismatch
+    {
+        /// number of objectives announced in the header
+        expected: u32,
+        /// number of objectives of the element
+        found: usize
+    },
+    /// Some elements of the file are dominated by other elements of the file, which is a sign of corruption
+    /// (see `ParetoFront::load_repair`).
+    DominatedElements(Vec<usize>),
+    /// An element could not be encoded or decoded.
+    Encoding(bincode::Error)
+}
+
+impl fmt::Display for BinaryError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            BinaryError::Io(error) => write!(f, "{error}"),
+            BinaryError::NotAFront => write!(f, "not a Pareto front file (invalid magic bytes)"),
+            BinaryError::UnsupportedVersion { major, minor } =>
+            {
+                write!(f, "unsupported format version {major}.{minor} (supported: {MAJOR_VERSION}.x)")
+            }
+            BinaryError::TooManyObjectives(nb_objectives) =>
+            {
+                write!(f, "elements have {nb_objectives} objectives, more than the format can store")
+            }
+            BinaryError::ObjectiveCountMismatch { expected, found } =>
+            {
+                write!(f, "element has {found} objectives but the file announces {expected}")
+            }
+            BinaryError::DominatedElements(indices) =>
+            {
+                write!(f, "elements at indices {indices:?} are dominated by other elements of the front")
+            }
+            BinaryError::Encoding(error) => write!(f, "{error}")
+        }
+    }
+}
+
+impl std::error::Error for BinaryError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match self
+        {
+            BinaryError::Io(error) => Some(error),
+            BinaryError::Encoding(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for BinaryError
+{
+    fn from(error: io::Error) -> Self
+    {
+        BinaryError::Io(error)
+    }
+}
+
+impl From<bincode::Error> for BinaryError
+{
+    fn from(error: bincode::Error) -> Self
+    {
+        match *error
+        {
+            bincode::ErrorKind::Io(error) => BinaryError::Io(error),
+            _ => BinaryError::Encoding(error)
+        }
+    }
+}
+
+/// Checks that `element` has the number of objectives announced in the header.
+fn check_objective_count<T: Objectives>(element: &T, nb_objectives: u32) -> Result<(), BinaryError>
+{
+    let found = element.nb_objectives();
+    if u32::try_from(found) == Ok(nb_objectives)
+    {
+        Ok(())
+    }
+    else
+    {
+        Err(BinaryError::ObjectiveCountMismatch { expected: nb_objectives, found })
+    }
+}
+
+/// Reads a little endian `u16`.
+fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16>
+{
+    let mut bytes = [0; 2];
+    reader.read_exact(&mut bytes)?;
+    Ok(u16::from_le_bytes(bytes))
+}
+
+/// Reads a little endian `u32`.
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32>
+{
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+/// Reads a little endian `u64`.
+fn read_u64<R: Read>(reader: &mut R) -> io::Result<u64>
+{
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+impl<T: Serialize + Objectives, D: Dominance<T>, O: FrontObserver<T>> ParetoFront<T, D, O>
+{
+    /// Saves the front to the file at `path` in a compact binary format.
+    ///
+    /// The front is first written to a temporary file, in the same directory, which is then renamed into `path`:
+    /// if saving fails, a previous file at `path` is left untouched.
+    ///
+    /// The file starts with a header in which all integers are little endian:
+    ///
+    /// | bytes | content |
+    /// |-------|---------|
+    /// | 8     | magic bytes `PARETOFR` |
+    /// | 2     | major version of the format (currently 1) |
+    /// | 2     | minor version of the format (currently 0) |
+    /// | 4     | number of bytes in the rest of the header (currently 12) |
+    /// | 4     | number of objectives of the elements (see `Objectives`, 0 for an empty front) |
+    /// | 8     | number of elements |
+    ///
+    /// followed by the elements, one after the other, encoded with serde and
+    /// [bincode](https://github.com/bincode-org/bincode) (variable-length integers, little endian).
+    ///
+    /// Minor versions of the format only add fields at the end of the header, which older readers skip,
+    /// while a new major version means that older readers refuse the file.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_binary` feature.**
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), BinaryError>
+    {
+        let path = path.as_ref();
+        let file_name =
+            path.file_name().ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "not a file path"))?;
+        let mut temporary_name = OsString::from(".");
+        temporary_name.push(file_name);
+        temporary_name.push(format!(".{}.tmp", std::process::id()));
+        let temporary_path = path.with_file_name(temporary_name);
+        // writes the temporary file, making sure it is on disk before replacing the previous file
+        let write_temporary = || -> Result<(), BinaryError> {
+            let mut writer = BufWriter::new(File::create(&temporary_path)?);
+            self.write_binary(&mut writer)?;
+            let file = writer.into_inner().map_err(io::IntoInnerError::into_error)?;
+            file.sync_all()?;
+            Ok(())
+        };
+        let result = write_temporary().and_then(|()| Ok(fs::rename(&temporary_path, path)?));
+        if result.is_err()
+        {
+            // the temporary file might not exist, there is nothing more to do if it cannot be removed
+            let _ = fs::remove_file(&temporary_path);
+        }
+        result
+    }
+
+    /// Writes the front to `writer` in the binary format described in `save`.
+    ///
+    /// The number of objectives of all elements is checked before anything is written.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_binary` feature.**
+    pub fn write_binary<W: Write>(&self, mut writer: W) -> Result<(), BinaryError>
+    {
+        let nb_objectives = self.iter().next().map_or(0, Objectives::nb_objectives);
+        let nb_objectives =
+            u32::try_from(nb_objectives).map_err(|_| BinaryError::TooManyObjectives(nb_objectives))?;
+        for element in self.iter()
+        {
+            check_objective_count(element, nb_objectives)?;
+        }
+        // header
+        writer.write_all(MAGIC)?;
+        writer.write_all(&MAJOR_VERSION.to_le_bytes())?;
+        writer.write_all(&MINOR_VERSION.to_le_bytes())?;
+        writer.write_all(&HEADER_LENGTH.to_le_bytes())?;
+        writer.write_all(&nb_objectives.to_le_bytes())?;
+        writer.write_all(&(self.len() as u64).to_le_bytes())?;
+        // elements
+        let encoding = element_encoding();
+        for element in self.iter()
+        {
+            encoding.serialize_into(&mut writer, element)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: DeserializeOwned + Objectives, D: Dominance<T> + Default, O: FrontObserver<T> + Default>
+    ParetoFront<T, D, O>
+{
+    /// Loads a front from a file written by `save`.
+    ///
+    /// Fails if the file is not in the binary format, uses a major version of the format unknown to this crate,
+    /// contains elements that do not have the number of objectives announced in the header,
+    /// or contains elements dominated by other elements of the file (see `load_repair`).
+    ///
+    /// This operation has `O(n²)` complexity where `n` is the number of elements in the front.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_binary` feature.**
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, BinaryError>
+    {
+        Self::read_binary(BufReader::new(File::open(path)?))
+    }
+
+    /// Loads a front from a file written by `save`, pushing all of its elements into an empty front
+    /// so that elements dominated by other elements are dropped.
+    ///
+    /// Meant to load fronts that might have been corrupted or written by another tool.
+    ///
+    /// This operation has `O(n²)` complexity where `n` is the number of elements in the front.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_binary` feature.**
+    pub fn load_repair<P: AsRef<Path>>(path: P) -> Result<Self, BinaryError>
+    {
+        Self::read_binary_repair(BufReader::new(File::open(path)?))
+    }
+
+    /// Reads a front written in the binary format described in `ParetoFront::save`, see `load`.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_binary` feature.**
+    pub fn read_binary<R: Read>(reader: R) -> Result<Self, BinaryError>
+    {
+        let elements = read_elements(reader)?;
+        Self::from_vec_strict(elements).map_err(BinaryError::DominatedElements)
+    }
+
+    /// Reads a front written in the binary format described in `ParetoFront::save`, see `load_repair`.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_binary` feature.**
+    pub fn read_binary_repair<R: Read>(reader: R) -> Result<Self, BinaryError>
+    {
+        let elements = read_elements(reader)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
+/// Reads the elements of a file in the binary format described in `ParetoFront::save`.
+fn read_elements<T: DeserializeOwned + Objectives, R: Read>(mut reader: R) -> Result<Vec<T>, BinaryError>
+{
+    // header
+    let mut magic = [0; MAGIC.len()];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC
+    {
+        return Err(BinaryError::NotAFront);
+    }
+    let major = read_u16(&mut reader)?;
+    let minor = read_u16(&mut reader)?;
+    let header_length = read_u32(&mut reader)?;
+    if (major != MAJOR_VERSION) || (header_length < HEADER_LENGTH)
+    {
+        return Err(BinaryError::UnsupportedVersion { major, minor });
+    }
+    let nb_objectives = read_u32(&mut reader)?;
+    let nb_elements = read_u64(&mut reader)?;
+    // skips the fields added by later minor versions
+    let extra_length = u64::from(header_length - HEADER_LENGTH);
+    io::copy(&mut (&mut reader).take(extra_length), &mut io::sink())?;
+    // elements
+    let encoding = element_encoding();
+    let mut elements = Vec::with_capacity(nb_elements.min(MAX_PREALLOCATED_ELEMENTS) as usize);
+    for _ in 0..nb_elements
+    {
+        let element: T = encoding.deserialize_from(&mut reader)?;
+        check_objective_count(&element, nb_objectives)?;
+        elements.push(element);
+    }
+    Ok(elements)
+}
@@ -0,0 +1,203 @@
+//! `Dominate` and `Objectives` implementations for common types.
+//!
+//! Arrays, slices, vectors and tuples are compared axis by axis, *minimizing* all axes.
+//! Axes that should be maximized can be wrapped in `Maximize`.
+//!
+//! Pointers (references, `Box`, `Rc`, `Arc`...) wrapped in `Forward` forward to the implementation of the type they point to,
+//! letting one store shared elements in a front without cloning them.
+use crate::{Dominate, NanPolicy, Objectives};
+use std::cmp::Ordering;
+use std::ops::Deref;
+
+/// Wraps a value such that it is *maximized* when used as an axis of an array, slice, vector or tuple.
+///
+/// ```rust
+/// # use pareto_front::{Maximize, ParetoFront};
+/// #
+/// // (cost, quality) pairs where cost is minimized and quality maximized
+/// let mut front = ParetoFront::new();
+/// front.push((35, Maximize(0.5)));
+/// front.push((350, Maximize(0.05))); // dominated
+/// front.push((5, Maximize(0.25)));
+/// assert_eq!(front.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Maximize<T>(pub T);
+
+impl<T: PartialOrd> PartialOrd for Maximize<T>
+{
+    /// Reversed ordering of the wrapped values.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl<T: Ord> Ord for Maximize<T>
+{
+    /// Reversed ordering of the wrapped values.
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        other.0.cmp(&self.0)
+    }
+}
+
+/// Compares `x` and `y` along a single axis to be minimized.
+/// Returns `false` if `x` is worse than (or incomparable with) `y`,
+/// otherwise sets `is_strictly_better` to `true` if `x` is strictly better than `y`.
+fn axis_is_better_or_equal<A: PartialOrd>(x: &A, y: &A, is_strictly_better: &mut bool) -> bool
+{
+    match x.partial_cmp(y)
+    {
+        Some(Ordering::Less) =>
+        {
+            *is_strictly_better = true;
+            true
+        }
+        Some(Ordering::Equal) => true,
+        _ => false
+    }
+}
+
+impl<T: PartialOrd> Dominate for [T]
+{
+    /// Returns `true` if `self` is smaller or equal to `x` along all axes and strictly smaller along at least one.
+    /// Slices of different lengths never dominate each other.
+    fn dominate(&self, x: &Self) -> bool
+    {
+        let mut is_strictly_better = false;
+        (self.len() == x.len())
+        && self.iter().zip(x.iter()).all(|(sa, xa)| axis_is_better_or_equal(sa, xa, &mut is_strictly_better))
+        && is_strictly_better
+    }
+}
+
+impl<T: PartialOrd, const N: usize> Dominate for [T; N]
+{
+    /// Returns `true` if `self` is smaller or equal to `x` along all axes and strictly smaller along at least one.
+    fn dominate(&self, x: &Self) -> bool
+    {
+        self.as_slice().dominate(x.as_slice())
+    }
+}
+
+impl<T: PartialOrd> Dominate for Vec<T>
+{
+    /// Returns `true` if `self` is smaller or equal to `x` along all axes and strictly smaller along at least one.
+    /// Vectors of different lengths never dominate each other.
+    fn dominate(&self, x: &Self) -> bool
+    {
+        self.as_slice().dominate(x.as_slice())
+    }
+}
+
+/// implements `Dominate` for a tuple whose fields are all `PartialOrd`
+macro_rules! impl_dominate_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: PartialOrd),+> Dominate for ($($name,)+)
+        {
+            /// Returns `true` if `self` is smaller or equal to `x` along all axes and strictly smaller along at least one.
+            fn dominate(&self, x: &Self) -> bool
+            {
+                let mut is_strictly_better = false;
+                $(axis_is_better_or_equal(&self.$index, &x.$index, &mut is_strictly_better) &&)+ is_strictly_better
+            }
+        }
+    };
+}
+
+impl_dominate_tuple!(A 0);
+impl_dominate_tuple!(A 0, B 1);
+impl_dominate_tuple!(A 0, B 1, C 2);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_dominate_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+impl Objectives for [f64]
+{
+    fn nb_objectives(&self) -> usize
+    {
+        self.len()
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self[index]
+    }
+}
+
+impl<const N: usize> Objectives for [f64; N]
+{
+    fn nb_objectives(&self) -> usize
+    {
+        N
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self[index]
+    }
+}
+
+impl Objectives for Vec<f64>
+{
+    fn nb_objectives(&self) -> usize
+    {
+        self.len()
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self[index]
+    }
+}
+
+/// Wraps a pointer (reference, `Box`, `Rc`, `Arc`...) such that it implements `Dominate` and `Objectives`
+/// by forwarding to the value it points to, letting one store shared elements in a front without cloning them.
+///
+/// A wrapper is used, rather than implementations for the pointer types themselves,
+/// as implementations enabled by a feature could conflict with the ones other crates write for their own types
+/// (such as `impl Dominate for Box<MyType>`).
+///
+/// ```rust
+/// # use pareto_front::{Forward, ParetoFront};
+/// # use std::rc::Rc;
+/// #
+/// // (cost, latency) pairs, shared between several fronts
+/// let elements = vec![Rc::new([35, 5]), Rc::new([350, 50]), Rc::new([5, 25])];
+/// let front: ParetoFront<_> = elements.iter().cloned().map(Forward).collect();
+/// assert_eq!(front.len(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Forward<P>(pub P);
+
+impl<P: Deref<Target: Dominate>> Dominate for Forward<P>
+{
+    fn dominate(&self, x: &Self) -> bool
+    {
+        P::Target::dominate(&self.0, &x.0)
+    }
+}
+
+impl<P: Deref<Target: Objectives>> Objectives for Forward<P>
+{
+    const NAN_POLICY: NanPolicy = P::Target::NAN_POLICY;
+
+    fn nb_objectives(&self) -> usize
+    {
+        P::Target::nb_objectives(&self.0)
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        P::Target::objective(&self.0, index)
+    }
+}
@@ -1,9 +1,19 @@
-use crate::{Dominate, ParetoFront};
+use crate::{Dominance, Dominate, FrontObserver, NaturalDominance, NoObserver, ParetoFront};
 use thread_local::ThreadLocal;
-use std::{cell::UnsafeCell, marker::Send};
+use super::poison::ignore_poison;
+use std::{marker::Send,
+      sync::{Mutex, MutexGuard}};
+#[cfg(feature = "pareto_front_stats")]
+use crate::FrontStats;
+
+/// Number of elements taken from the top of each thread-local front by `ConcurrentParetoFront::compact`.
+const ELITES_PER_FRONT: usize = 16;
 
 /// Represents a Pareto front that can be pushed into concurrently.
 ///
+/// Each thread pushes into its own front, protected by a `Mutex` that is only contended
+/// when the fronts are read by another thread (see `snapshot` and `dominate`).
+///
 /// As this implementation is based on thread-local fronts,
 /// one would get better performance by having explicitely one `ParetoFront` per thread
 /// and merging them when needed.
@@ -11,12 +21,64 @@ use std::{cell::UnsafeCell, marker::Send};
 /// We expect this implementation to use approximately `O(t*n)` memory
 /// where `t` is the number of threads used
 /// and `n` is the size of the corresponding sequential Pareto front.
+/// Calling `compact` periodically removes the elements of a thread-local front that are dominated by other threads
+/// and shares the best elements of each thread with all threads, so that later pushes can be rejected early.
+///
+/// The dominance relation is given by the comparator `D`, cloned into each thread-local front.
+/// The observer `O` is also cloned into each thread-local front,
+/// it should thus share its state between clones (using an `Arc`, an atomic or a channel for example).
 ///
 /// **NOTE: This struct is only available when enabling the `pareto_front_concurrent` feature.**
-#[derive(Default, Debug)]
-pub struct ConcurrentParetoFront<T: Dominate + Send>
+#[derive(Debug)]
+pub struct ConcurrentParetoFront<T: Send, D: Send = NaturalDominance, O: Send = NoObserver>
+{
+    inner_front: ThreadLocal<Mutex<ThreadLocalFront<T, D, O>>>,
+    /// elites shared by the last call to `compact`, given to the thread-local fronts created later
+    elites: Mutex<SharedElites<T>>,
+    dominance: D,
+    observer: O
+}
+
+/// Elites shared by the last call to `ConcurrentParetoFront::compact`.
+#[derive(Debug)]
+struct SharedElites<T>
+{
+    elites: Vec<T>,
+    /// copies the elites, stored here as `push` does not require `T: Clone` while `compact` does
+    clone: fn(&[T]) -> Vec<T>
+}
+
+impl<T> Default for SharedElites<T>
+{
+    /// No elites.
+    fn default() -> Self
+    {
+        SharedElites { elites: Vec::new(), clone: |_| Vec::new() }
+    }
+}
+
+/// Front of a single thread, along with the elites shared by the last call to `ConcurrentParetoFront::compact`.
+#[derive(Debug)]
+struct ThreadLocalFront<T, D, O>
 {
-    inner_front: ThreadLocal<UnsafeCell<ParetoFront<T>>>
+    front: ParetoFront<T, D, O>,
+    /// best elements of all thread-local fronts, candidates dominated by one of them are rejected without going through `front`
+    elites: Vec<T>
+}
+
+impl<T, D: Dominance<T>, O: FrontObserver<T>> ThreadLocalFront<T, D, O>
+{
+    /// Wraps a front.
+    fn new(front: ParetoFront<T, D, O>, elites: Vec<T>) -> Self
+    {
+        ThreadLocalFront { front, elites }
+    }
+
+    /// Adds `new_element` to the front unless it is dominated by an elite.
+    fn push(&mut self, new_element: T) -> bool
+    {
+        !self.front.reject_if_dominated_by(&self.elites, &new_element) && self.front.push(new_element)
+    }
 }
 
 impl<T: Dominate + Send> ConcurrentParetoFront<T>
@@ -24,7 +86,102 @@ impl<T: Dominate + Send> ConcurrentParetoFront<T>
     /// Constructs a new, empty, concurrent Pareto front.
     pub fn new() -> Self
     {
-        ConcurrentParetoFront { inner_front: ThreadLocal::new() }
+        ConcurrentParetoFront { inner_front: ThreadLocal::new(),
+                                elites: Mutex::default(),
+                                dominance: NaturalDominance,
+                                observer: NoObserver }
+    }
+}
+
+impl<T: Send, D: Dominance<T> + Clone + Send> ConcurrentParetoFront<T, D>
+{
+    /// Constructs a new, empty, concurrent Pareto front using `dominance` as its dominance relation.
+    pub fn with_comparator(dominance: D) -> Self
+    {
+        ConcurrentParetoFront { inner_front: ThreadLocal::new(),
+                                elites: Mutex::default(),
+                                dominance,
+                                observer: NoObserver }
+    }
+}
+
+impl<T: Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send>
+    ConcurrentParetoFront<T, D, O>
+{
+    /// Attaches `observer` to the front, a clone of it will be notified of all elements entering and leaving each thread-local front.
+    ///
+    /// Elements already in the front are kept but not reported to the observer.
+    /// If the front is not empty, this operation has the complexity of `into_sequential`.
+    pub fn with_observer<O2: FrontObserver<T> + Clone + Send>(mut self,
+                                                              observer: O2)
+                                                              -> ConcurrentParetoFront<T, D, O2>
+    {
+        if self.inner_front.iter_mut().next().is_none()
+        {
+            ConcurrentParetoFront { inner_front: ThreadLocal::new(),
+                                    elites: Mutex::default(),
+                                    dominance: self.dominance,
+                                    observer }
+        }
+        else
+        {
+            self.into_sequential().with_observer(observer).into()
+        }
+    }
+
+    /// Returns the observer cloned into each thread-local front.
+    pub fn observer(&self) -> &O
+    {
+        &self.observer
+    }
+
+    /// Returns the comparator used as dominance relation by the front.
+    pub fn comparator(&self) -> &D
+    {
+        &self.dominance
+    }
+
+    /// Returns the sum of the counters describing the work performed by all thread-local fronts (see `FrontStats`).
+    ///
+    /// This can be called while other threads are pushing: each thread-local front is locked in turn,
+    /// the result is thus a sum of per-thread snapshots rather than a snapshot of the whole front.
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_stats` feature.**
+    #[cfg(feature = "pareto_front_stats")]
+    pub fn stats(&self) -> FrontStats
+    {
+        let mut stats = FrontStats::default();
+        for front in self.inner_front.iter()
+        {
+            stats += *lock(front).front.stats();
+        }
+        stats
+    }
+
+    /// Sets the counters describing the work performed by all thread-local fronts back to zero.
+    ///
+    /// Like `stats`, this can be called while other threads are pushing, each thread-local front being locked in turn.
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_stats` feature.**
+    #[cfg(feature = "pareto_front_stats")]
+    pub fn reset_stats(&self)
+    {
+        for front in self.inner_front.iter()
+        {
+            lock(front).front.reset_stats();
+        }
+    }
+
+    /// Returns the Pareto front associated with the current thread, creating it if needed.
+    fn thread_local_front(&self) -> &Mutex<ThreadLocalFront<T, D, O>>
+    {
+        self.inner_front.get_or(|| {
+                            let front = ParetoFront::with_comparator(self.dominance.clone());
+                            let shared_elites = lock(&self.elites);
+                            let elites = (shared_elites.clone)(&shared_elites.elites);
+                            Mutex::new(ThreadLocalFront::new(front.with_observer(self.observer.clone()),
+                                                             elites))
+                        })
     }
 
     /// Adds `new_element` to the Pareto front.
@@ -36,80 +193,188 @@ impl<T: Dominate + Send> ConcurrentParetoFront<T>
     /// and `t` the number of threads used.
     /// It is cache friendly and optimized to favour early stopping.
     ///
+    /// Candidates are first checked against the elites shared by the last call to `compact`, if any.
+    ///
     /// Note that this operation does *not* use any interior paralelism.
     /// Rather, it is meant to be called in parallel.
     pub fn push(&self, new_element: T) -> bool
     {
-        // gets a mutable *pointer* to the Pareto front associated with the current thread
-        let front_ptr = self.inner_front.get_or_default().get();
-        // converts the pointer into a mutable reference
-        // Note: safe because only one thread can access a thread-local front
-        //       this has been validated with a RefCell
-        let front = unsafe { &mut *front_ptr };
-        // push the new element in the Pareto front
-        front.push(new_element)
+        // the lock is only contended if another thread is reading the fronts
+        lock(self.thread_local_front()).push(new_element)
     }
 
-    /// Turns the concurrent Pareto front into a, sequential, `ParetoFront`.
+    /// Returns `true` if at least one element of the Pareto front dominates `new_element`.
     ///
-    /// This operation has complexity `O(n²)`
+    /// This operation has `O(n)` complexity (where `n` is the number of elements in all thread-local fronts),
+    /// the thread-local fronts are locked one at a time.
+    pub fn dominate(&self, new_element: &T) -> bool
+    {
+        self.inner_front.iter().any(|front| lock(front).front.dominate(new_element))
+    }
+
+    /// Returns a copy of the current content of the Pareto front, without consuming the concurrent front.
+    /// The snapshot does not have an observer attached.
+    ///
+    /// Elements pushed while the snapshot is being taken might or might not be part of it.
+    ///
+    /// The thread-local fronts are locked one at a time, only while they are being copied,
+    /// the copies are then merged with `ParetoFront::merge_all` which has complexity `O(n²)`
     /// where `n` is the size of the Pareto front.
+    pub fn snapshot(&self) -> ParetoFront<T, D>
+        where T: Clone
+    {
+        let empty_front = ParetoFront::with_comparator(self.dominance.clone());
+        // `union` with an empty front copies a thread-local front without its observer
+        let fronts: Vec<_> =
+            self.inner_front.iter().map(|front| empty_front.union(&lock(front).front)).collect();
+        ParetoFront::merge_all(fronts).unwrap_or(empty_front)
+    }
+
+    /// Removes, from each thread-local front, the elements that are dominated by elements of other thread-local fronts,
+    /// and shares the best elements of all thread-local fronts with all threads.
     ///
-    /// Note that this operation does *not* use any interior paralelism.
-    pub fn into_sequential(self) -> ParetoFront<T>
+    /// The first elements of each thread-local front (which, thanks to the reordering done by `ParetoFront`,
+    /// are the ones that dominated the most candidates) are collected as elites.
+    /// Each thread-local front is then pruned of the elements dominated by an elite
+    /// and receives a copy of the elites (as do the thread-local fronts created later, when new threads start pushing):
+    /// until the next call to `compact`, `push` rejects candidates dominated by an elite
+    /// before looking at the thread-local front, a candidate is thus discarded early even if the element dominating it
+    /// was found by another thread.
+    /// This reduces the memory used by the front and speeds up later pushes
+    /// without taking a global lock: the thread-local fronts are locked one at a time.
+    /// It can be called periodically by any thread while other threads are pushing.
+    ///
+    /// Removed elements are reported as evicted to the observer and candidates dominated by an elite as rejected.
+    /// Nothing is done if the dominance relation is not transitive
+    /// (as an elite might later be removed from its front by an element that does not dominate the pruned elements).
+    ///
+    /// This operation has `O(e*n)` complexity
+    /// where `e` is the number of elites (at most 16 per thread-local front)
+    /// and `n` is the number of elements in all thread-local fronts.
+    /// Each thread-local front stores its own copy of the elites.
+    pub fn compact(&self)
+        where T: Clone
+    {
+        if !self.dominance.is_transitive()
+        {
+            return;
+        }
+
+        // collects the elites, keeping only the ones that are not dominated by another elite
+        let mut elites = ParetoFront::with_comparator(self.dominance.clone());
+        for front in self.inner_front.iter()
+        {
+            let front = lock(front);
+            elites.extend(front.front.iter().take(ELITES_PER_FRONT).cloned());
+        }
+
+        // prunes each thread-local front and gives it the elites
+        // NOTE: an elite cannot be dominated by another elite, and thus cannot be removed,
+        //       but any of them might be evicted concurrently by an element that, by transitivity, dominates what it dominated
+        for front in self.inner_front.iter()
+        {
+            let mut front = lock(front);
+            front.front.retain(|x| !elites.dominate(x));
+            front.elites = elites.as_slice().to_vec();
+        }
+        *lock(&self.elites) = SharedElites { elites: elites.into(), clone: <[T]>::to_vec };
+    }
+
+    /// Keeps only the elements of all thread-local fronts for which `predicate` returns `true`.
+    ///
+    /// Elites are dropped as the elements that made them relevant might have been removed.
+    pub(crate) fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F)
+    {
+        // we have exclusive access to the thread-local fronts, no need to lock them
+        for front in self.inner_front.iter_mut()
+        {
+            let front = get_mut(front);
+            front.front.retain(&mut predicate);
+            front.elites.clear();
+        }
+        *get_mut(&mut self.elites) = SharedElites::default();
+    }
+
+    /// Turns the concurrent Pareto front into a, sequential, `ParetoFront`.
+    ///
+    /// Elements of a thread-local front that are dominated by the elements of another thread-local front
+    /// are reported as evicted to the observer.
+    ///
+    /// The thread-local fronts are merged pairwise, as a binary tree (see `ParetoFront::merge_all`),
+    /// this operation has complexity `O(n²)` where `n` is the size of the Pareto front.
+    ///
+    /// Merges are done in parallel if the `pareto_front_rayon` feature is enabled.
+    pub fn into_sequential(self) -> ParetoFront<T, D, O>
     {
-        // NOTE: this could be turned into a parallel reduce
-        //       but, tests with `rayon` did not bring any significant speed benefits
-        //       however, paralelism might become beneficial on a large (16+) number of cores
-        self.inner_front
-            .into_iter()
-            .map(|r| r.into_inner()) // remove UnsafeCells
-            .reduce(|mut front_acc, front| {
-                // merge all fronts into one
-                front_acc.merge(front);
-                front_acc
-            })
-            .unwrap_or_default() // returns an empty front if there was no thread-local front
+        let fronts: Vec<_> = self.inner_front.into_iter().map(|front| into_inner(front).front).collect();
+        // merges the fronts as a binary tree, independent merges can then run in parallel
+        #[cfg(feature = "pareto_front_rayon")]
+        let front = ParetoFront::par_merge_tree(fronts, ParetoFront::merge_accepted);
+        #[cfg(not(feature = "pareto_front_rayon"))]
+        let front = ParetoFront::merge_tree(fronts, ParetoFront::merge_accepted);
+        // returns an empty front if there was no thread-local front
+        front.unwrap_or_else(|| ParetoFront::with_comparator(self.dominance).with_observer(self.observer))
     }
 }
 
-impl<T: Dominate + Send> From<ConcurrentParetoFront<T>> for Vec<T>
+impl<T: Send, D: Dominance<T> + Clone + Send + Default, O: FrontObserver<T> + Clone + Send + Default> Default
+    for ConcurrentParetoFront<T, D, O>
+{
+    /// Default value.
+    fn default() -> Self
+    {
+        // Manually implemented so as to not require `T` to implement `Default`.
+        ConcurrentParetoFront { inner_front: ThreadLocal::new(),
+                                elites: Mutex::default(),
+                                dominance: D::default(),
+                                observer: O::default() }
+    }
+}
+
+impl<T: Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send>
+    From<ConcurrentParetoFront<T, D, O>> for Vec<T>
 {
     /// Converts the concurrent Pareto front into a vector.
     /// This operation has the complexity of `into_sequential`.
-    fn from(front: ConcurrentParetoFront<T>) -> Vec<T>
+    fn from(front: ConcurrentParetoFront<T, D, O>) -> Vec<T>
     {
         front.into_sequential().into()
     }
 }
 
-impl<T: Dominate + Send> From<ConcurrentParetoFront<T>> for ParetoFront<T>
+impl<T: Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send>
+    From<ConcurrentParetoFront<T, D, O>> for ParetoFront<T, D, O>
 {
     /// Converts the concurrent Pareto front into a `ParetoFront`.
     /// This operation has the complexity of `into_sequential`.
-    fn from(front: ConcurrentParetoFront<T>) -> ParetoFront<T>
+    fn from(front: ConcurrentParetoFront<T, D, O>) -> ParetoFront<T, D, O>
     {
         front.into_sequential()
     }
 }
 
-impl<T: Dominate + Send> From<ParetoFront<T>> for ConcurrentParetoFront<T>
+impl<T: Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send> From<ParetoFront<T, D, O>>
+    for ConcurrentParetoFront<T, D, O>
 {
     /// Converts a `ParetoFront` into a concurrent Pareto front.
     /// this operation has complexity `O(1)`.
-    fn from(front: ParetoFront<T>) -> Self
+    fn from(front: ParetoFront<T, D, O>) -> Self
     {
         // creates new, empty, concurrent Pareto front
-        let result = ConcurrentParetoFront::new();
+        let result = ConcurrentParetoFront { inner_front: ThreadLocal::new(),
+                                             elites: Mutex::default(),
+                                             dominance: front.comparator().clone(),
+                                             observer: front.observer().clone() };
         // tries to get a thread-local pareto front
         // as the front is empty, it triggers the call to front
-        result.inner_front.get_or(|| UnsafeCell::new(front));
+        result.inner_front.get_or(|| Mutex::new(ThreadLocalFront::new(front, Vec::new())));
         // returns result
         result
     }
 }
 
-impl<T: Dominate + Send> IntoIterator for ConcurrentParetoFront<T>
+impl<T: Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send> IntoIterator
+    for ConcurrentParetoFront<T, D, O>
 {
     type Item = T;
     type IntoIter = std::vec::IntoIter<T>;
@@ -122,7 +387,8 @@ impl<T: Dominate + Send> IntoIterator for ConcurrentParetoFront<T>
     }
 }
 
-impl<T: Dominate + Send> FromIterator<T> for ConcurrentParetoFront<T>
+impl<T: Send, D: Dominance<T> + Clone + Send + Default, O: FrontObserver<T> + Clone + Send + Default>
+    FromIterator<T> for ConcurrentParetoFront<T, D, O>
 {
     /// Implements the `FromIterator` trait to enable the collection of an iterator into a `ConcurrentParetoFront`.
     ///
@@ -130,13 +396,14 @@ impl<T: Dominate + Send> FromIterator<T> for ConcurrentParetoFront<T>
     /// it does *not* use any interior paralelism.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
     {
-        let mut front = ConcurrentParetoFront::new();
+        let mut front = ConcurrentParetoFront::default();
         front.extend(iter); // we reuse the implementation of extend
         front
     }
 }
 
-impl<T: Dominate + Send> Extend<T> for ConcurrentParetoFront<T>
+impl<T: Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send> Extend<T>
+    for ConcurrentParetoFront<T, D, O>
 {
     /// Implements the `Extend` trait to extend a `ConcurrentParetoFront` with the content of an iterator.
     ///
@@ -144,13 +411,77 @@ impl<T: Dominate + Send> Extend<T> for ConcurrentParetoFront<T>
     /// it does *not* use any interior paralelism.
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
     {
-        // gets a mutable *pointer* to the Pareto front associated with the current thread
-        let front_ptr = self.inner_front.get_or_default().get();
-        // converts the pointer into a mutable reference
-        // Note: safe because only one thread can access a thread-local front
-        //       this has been validated with a RefCell
-        let front = unsafe { &mut *front_ptr };
-        // push the new elements in the Pareto front
-        front.extend(iter)
+        // the lock is taken once for all the elements
+        let mut front = lock(self.thread_local_front());
+        for new_element in iter
+        {
+            front.push(new_element);
+        }
+    }
+}
+
+/// Locks a thread-local front, ignoring poisoning (see `ignore_poison` for what this implies).
+fn lock<F>(front: &Mutex<F>) -> MutexGuard<'_, F>
+{
+    ignore_poison(front.lock())
+}
+
+/// Gives mutable access to a thread-local front we have exclusive access to, ignoring poisoning.
+fn get_mut<F>(front: &mut Mutex<F>) -> &mut F
+{
+    ignore_poison(front.get_mut())
+}
+
+/// Extracts a thread-local front, ignoring poisoning.
+fn into_inner<F>(front: Mutex<F>) -> F
+{
+    ignore_poison(front.into_inner())
+}
+
+/// Dominance relation on references, forwarding to the relation on the elements.
+#[cfg(feature = "serde")]
+struct RefDominance<'a, D>(&'a D);
+
+#[cfg(feature = "serde")]
+impl<'a, T, D: Dominance<T>> Dominance<&'a T> for RefDominance<'a, D>
+{
+    fn dominate(&self, x: &&'a T, y: &&'a T) -> bool
+    {
+        self.0.dominate(x, y)
+    }
+
+    fn is_transitive(&self) -> bool
+    {
+        self.0.is_transitive()
+    }
+}
+
+/// The concurrent front is serialized as the `ParetoFront` it would be turned into by `into_sequential`,
+/// pushes from other threads are blocked while the front is being serialized.
+#[cfg(feature = "serde")]
+impl<T: Send + serde::Serialize, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send>
+    serde::Serialize for ConcurrentParetoFront<T, D, O>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        // merges references to the elements, to avoid cloning them
+        let fronts: Vec<_> = self.inner_front.iter().map(lock).collect();
+        let mut merged_front = ParetoFront::with_comparator(RefDominance(&self.dominance));
+        merged_front.extend(fronts.iter().flat_map(|front| front.front.iter()));
+        merged_front.serialize(serializer)
+    }
+}
+
+/// The deserialized front is stored in a single thread-local front, as when converting a `ParetoFront`.
+/// The comparator and observer are *not* serialized, a deserialized front uses their default values.
+#[cfg(feature = "serde")]
+impl<'de,
+     T: Send + serde::Deserialize<'de>,
+     D: Dominance<T> + Clone + Send + Default,
+     O: FrontObserver<T> + Clone + Send + Default> serde::Deserialize<'de> for ConcurrentParetoFront<T, D, O>
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error>
+    {
+        ParetoFront::deserialize(deserializer).map(ConcurrentParetoFront::from)
     }
 }
@@ -0,0 +1,312 @@
+use crate::{Dominance, FrontObserver, ParetoFront};
+use csv::{ReaderBuilder, StringRecord, WriterBuilder};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::{fmt, io};
+
+/// Column of a CSV file, designated by its name (if the file has headers) or its index (starting at 0).
+///
+/// **NOTE: This enum is only available when enabling the `pareto_front_csv` feature.**
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CsvColumn
+{
+    /// Column with the given header.
+    Name(String),
+    /// Column at the given index, starting at 0.
+    Index(usize)
+}
+
+impl From<&str> for CsvColumn
+{
+    fn from(name: &str) -> Self
+    {
+        CsvColumn::Name(name.to_string())
+    }
+}
+
+impl From<String> for CsvColumn
+{
+    fn from(name: String) -> Self
+    {
+        CsvColumn::Name(name)
+    }
+}
+
+impl From<usize> for CsvColumn
+{
+    fn from(index: usize) -> Self
+    {
+        CsvColumn::Index(index)
+    }
+}
+
+impl CsvColumn
+{
+    /// Returns the index of the column in a file with the given `headers` (`None` if the file has no headers),
+    /// or `None` if the column is not in the file.
+    ///
+    /// Without headers, names cannot be found and indices are returned unchecked (rows might differ in length).
+    ///
+    /// This is the lookup used by `ParetoFront::from_csv_reader` and by the `pareto` command-line tool.
+    pub fn find(&self, headers: Option<&[&str]>) -> Option<usize>
+    {
+        match (self, headers)
+        {
+            (CsvColumn::Index(index), Some(headers)) => (*index < headers.len()).then_some(*index),
+            (CsvColumn::Index(index), None) => Some(*index),
+            (CsvColumn::Name(name), Some(headers)) => headers.iter().position(|header| *header == name),
+            (CsvColumn::Name(_), None) => None
+        }
+    }
+}
+
+impl fmt::Display for CsvColumn
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            CsvColumn::Name(name) => write!(f, "'{name}'"),
+            CsvColumn::Index(index) => write!(f, "{index}")
+        }
+    }
+}
+
+/// Describes the layout of a CSV file read by `ParetoFront::from_csv_reader` or written by `ParetoFront::write_csv`.
+///
+/// By default, the file is comma separated, has headers and all columns are used.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_csv` feature.**
+#[derive(Clone, Debug)]
+pub struct CsvOptions
+{
+    has_headers: bool,
+    delimiter: u8,
+    columns: Option<Vec<CsvColumn>>
+}
+
+impl Default for CsvOptions
+{
+    fn default() -> Self
+    {
+        CsvOptions { has_headers: true, delimiter: b',', columns: None }
+    }
+}
+
+impl CsvOptions
+{
+    /// Sets whether the first line of the file contains headers (`true` by default).
+    ///
+    /// When reading, headers are matched against the names of the fields of the elements,
+    /// otherwise, fields are read in order.
+    pub fn with_headers(mut self, has_headers: bool) -> Self
+    {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// Sets the delimiter between fields (`b','` by default, use `b'\t'` for TSV files).
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self
+    {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Only reads the given columns, in the given order, ignoring the other columns of the file.
+    ///
+    /// This is useful when the file contains columns that are not objectives (such as identifiers or comments).
+    /// This setting is ignored when writing a front.
+    pub fn with_columns<C: Into<CsvColumn>, I: IntoIterator<Item = C>>(mut self, columns: I) -> Self
+    {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+/// Kind of error encountered while reading or writing a CSV file.
+///
+/// **NOTE: This enum is only available when enabling the `pareto_front_csv` feature.**
+#[derive(Debug)]
+pub enum CsvErrorKind
+{
+    /// The file could not be read, written or parsed, or a row could not be converted into an element.
+    Csv(csv::Error),
+    /// A selected column does not exist.
+    MissingColumn(CsvColumn)
+}
+
+/// Error encountered while reading or writing a CSV file.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_csv` feature.**
+#[derive(Debug)]
+pub struct CsvError
+{
+    /// Line of the file (starting at 1) at which the error occured, if known.
+    pub line: Option<u64>,
+    /// What went wrong.
+    pub kind: CsvErrorKind
+}
+
+impl fmt::Display for CsvError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if let Some(line) = self.line
+        {
+            write!(f, "line {line}: ")?;
+        }
+        match &self.kind
+        {
+            CsvErrorKind::Csv(error) => write!(f, "{error}"),
+            CsvErrorKind::MissingColumn(column) => write!(f, "column {column} does not exist")
+        }
+    }
+}
+
+impl std::error::Error for CsvError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match &self.kind
+        {
+            CsvErrorKind::Csv(error) => Some(error),
+            CsvErrorKind::MissingColumn(_) => None
+        }
+    }
+}
+
+impl From<csv::Error> for CsvError
+{
+    fn from(error: csv::Error) -> Self
+    {
+        CsvError { line: error.position().map(|position| position.line()), kind: CsvErrorKind::Csv(error) }
+    }
+}
+
+impl From<io::Error> for CsvError
+{
+    fn from(error: io::Error) -> Self
+    {
+        csv::Error::from(error).into()
+    }
+}
+
+impl<T: DeserializeOwned, D: Dominance<T> + Default, O: FrontObserver<T> + Default> ParetoFront<T, D, O>
+{
+    /// Builds a Pareto front out of the rows of a CSV file, each row being deserialized into an element with serde.
+    ///
+    /// Rows are pushed into the front as they are read, the file thus never needs to fit in memory.
+    /// Errors report the line of the offending row.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_csv` feature.**
+    ///
+    /// ```rust
+    /// # use pareto_front::{CsvOptions, Dominate, ParetoFront};
+    /// #
+    /// #[derive(PartialEq, serde::Deserialize)]
+    /// struct ParetoElement
+    /// {
+    ///     cost: usize, // to be minimized
+    ///     quality: f32, // to be maximized
+    /// }
+    /// #
+    /// # impl Dominate for ParetoElement
+    /// # {
+    /// #     fn dominate(&self, x: &Self) -> bool
+    /// #     {
+    /// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    /// #     }
+    /// # }
+    ///
+    /// let file = "name,cost,quality\nfirst,35,0.5\nsecond,5,0.25\nthird,50,0.25\n";
+    ///
+    /// // the `name` column is not part of the elements
+    /// let options = CsvOptions::default().with_columns(["cost", "quality"]);
+    /// let front: ParetoFront<ParetoElement> = ParetoFront::from_csv_reader(file.as_bytes(), &options).unwrap();
+    /// assert_eq!(front.len(), 2);
+    /// ```
+    pub fn from_csv_reader<R: io::Read>(reader: R, options: &CsvOptions) -> Result<Self, CsvError>
+    {
+        let mut reader = ReaderBuilder::new().has_headers(options.has_headers)
+                                             .delimiter(options.delimiter)
+                                             .from_reader(reader);
+        let headers = if options.has_headers { Some(reader.headers()?.clone()) } else { None };
+
+        // converts the selected columns into indices
+        let column_indices = match &options.columns
+        {
+            None => None,
+            Some(columns) =>
+            {
+                let header_names: Option<Vec<&str>> =
+                    headers.as_ref().map(|headers| headers.iter().collect());
+                let indices = columns.iter()
+                                     .map(|column| column_index(column, header_names.as_deref()))
+                                     .collect::<Result<Vec<_>, _>>()?;
+                Some(indices)
+            }
+        };
+        let selected_headers = match (&headers, &column_indices)
+        {
+            (Some(headers), Some(indices)) => Some(indices.iter().map(|&index| &headers[index]).collect()),
+            _ => headers
+        };
+
+        let mut front = ParetoFront::default();
+        let mut record = StringRecord::new();
+        while reader.read_record(&mut record)?
+        {
+            let line = record.position().map(|position| position.line());
+            let element = match &column_indices
+            {
+                None => record.deserialize(selected_headers.as_ref()),
+                Some(indices) =>
+                {
+                    let mut selected_record =
+                        StringRecord::with_capacity(record.as_slice().len(), indices.len());
+                    for &index in indices
+                    {
+                        // rows might be shorter than expected when there are no headers
+                        let missing_column = CsvErrorKind::MissingColumn(CsvColumn::Index(index));
+                        let field = record.get(index).ok_or(CsvError { line, kind: missing_column })?;
+                        selected_record.push_field(field);
+                    }
+                    selected_record.deserialize(selected_headers.as_ref())
+                }
+            };
+            let element = element.map_err(|error| CsvError { line, kind: CsvErrorKind::Csv(error) })?;
+            front.push(element);
+        }
+        Ok(front)
+    }
+}
+
+/// Returns the index of `column` (see `CsvColumn::find`), failing if it is not in `headers`.
+///
+/// Without headers, indices are checked on each row.
+fn column_index(column: &CsvColumn, headers: Option<&[&str]>) -> Result<usize, CsvError>
+{
+    column.find(headers).ok_or_else(|| CsvError { line: headers.map(|_| 1),
+                                                  kind: CsvErrorKind::MissingColumn(column.clone()) })
+}
+
+impl<T: Serialize, D: Dominance<T>, O: FrontObserver<T>> ParetoFront<T, D, O>
+{
+    /// Writes the elements of the front as the rows of a CSV file, each element being serialized with serde.
+    ///
+    /// Headers, if requested, are the names of the fields of the elements.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_csv` feature.**
+    pub fn write_csv<W: io::Write>(&self, writer: W, options: &CsvOptions) -> Result<(), CsvError>
+    {
+        let mut writer = WriterBuilder::new().has_headers(options.has_headers)
+                                             .delimiter(options.delimiter)
+                                             .from_writer(writer);
+        for element in self.iter()
+        {
+            writer.serialize(element)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
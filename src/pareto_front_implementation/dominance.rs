@@ -0,0 +1,45 @@
+use crate::Dominate;
+
+/// Comparator defining the dominance relation used by a Pareto front.
+///
+/// This lets one use a dominance relation other than the one given by the `Dominate` implementation of the elements.
+pub trait Dominance<T>
+{
+    /// Returns `true` if `x` dominates `y`.
+    /// By convention, it usually returns `false` if `x` is equal to `y`.
+    fn dominate(&self, x: &T, y: &T) -> bool;
+
+    /// Returns `true` if the dominance relation is transitive
+    /// (if `x` dominates `y` and `y` dominates `z` then `x` dominates `z`).
+    ///
+    /// Transitivity lets the Pareto front stop some of its scans early,
+    /// a non-transitive relation will fall back to a slightly slower algorithm.
+    fn is_transitive(&self) -> bool
+    {
+        true
+    }
+}
+
+/// Dominance relation given by the `Dominate` implementation of the elements.
+///
+/// This is the default dominance relation used by a `ParetoFront`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NaturalDominance;
+
+impl<T: Dominate> Dominance<T> for NaturalDominance
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        x.dominate(y)
+    }
+}
+
+/// Lets any closure taking two elements and returning `true` if the first one dominates the second be used as a comparator.
+impl<T, F: Fn(&T, &T) -> bool> Dominance<T> for F
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        self(x, y)
+    }
+}
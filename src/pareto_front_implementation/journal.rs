@@ -0,0 +1,343 @@
+use crate::{Dominance, FrontObserver, ParetoFront};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::io::{Seek, SeekFrom};
+use std::{fmt, fs, io, mem};
+
+/// Observer appending every element accepted by a front to a [JSON Lines](https://jsonlines.org/) journal.
+///
+/// The journal can be used to rebuild the front after a crash (see `ParetoFront::from_journal`):
+/// as elements are never removed from the journal, rebuilding re-pushes all of them and evicted elements drop out again.
+/// `ParetoFront::compact_journal` rewrites the journal so that it only contains the current front.
+///
+/// Each element is written as a single line, with a single call to `write_all`, and the writer is flushed after each line.
+/// Flushing hands the line to the operating system, which covers crashes of the process but not power losses:
+/// to also cover those, a journal written to a file can sync each line to disk (`with_sync`), at a much higher cost.
+/// As `FrontObserver` cannot report failures, the first error is kept (`error`), later elements are not written
+/// (to avoid leaving a gap in the journal) and the error is returned by `flush`.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_journal` feature.**
+///
+/// ```rust
+/// # use pareto_front::{Dominate, JournalObserver, ParetoFront};
+/// #
+/// # #[derive(PartialEq, serde::Serialize, serde::Deserialize)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// // in practice, the journal would be a file (see `ParetoFront::recover_journal`)
+/// let mut front = ParetoFront::new().with_observer(JournalObserver::new(Vec::new()));
+/// front.push(ParetoElement { cost: 35, quality: 0.5 });
+/// front.push(ParetoElement { cost: 5, quality: 0.25 });
+/// front.push(ParetoElement { cost: 50, quality: 0.25 }); // dominated, not journaled
+/// front.push(ParetoElement { cost: 1, quality: 0.75 }); // evicts both previous elements
+/// front.observer_mut().flush().unwrap();
+///
+/// // after a crash, the front is rebuilt from the journal
+/// let journal = front.observer().get_ref();
+/// let rebuilt_front: ParetoFront<ParetoElement> = ParetoFront::from_journal(journal.as_slice()).unwrap();
+/// assert_eq!(rebuilt_front.len(), 1);
+/// ```
+#[derive(Debug)]
+pub struct JournalObserver<W>
+{
+    writer: W,
+    /// called after each line when syncing is enabled, see `with_sync`
+    sync: Option<fn(&mut W) -> io::Result<()>>,
+    error: Option<io::Error>,
+    /// reused between lines to avoid an allocation per element
+    buffer: Vec<u8>
+}
+
+impl<W: io::Write> JournalObserver<W>
+{
+    /// Builds an observer appending accepted elements to `writer`.
+    pub fn new(writer: W) -> Self
+    {
+        JournalObserver { writer, sync: None, error: None, buffer: Vec::new() }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W
+    {
+        &self.writer
+    }
+
+    /// Returns the first error encountered while writing the journal, if any.
+    pub fn error(&self) -> Option<&io::Error>
+    {
+        self.error.as_ref()
+    }
+
+    /// Flushes the underlying writer.
+    ///
+    /// Returns (and clears) the first error encountered while writing the journal, if any,
+    /// after which elements are journaled again.
+    pub fn flush(&mut self) -> io::Result<()>
+    {
+        match self.error.take()
+        {
+            Some(error) => Err(error),
+            None => self.writer.flush()
+        }
+    }
+
+    /// Returns the underlying writer, dropping any error that was not reported by `flush`.
+    pub fn into_inner(self) -> W
+    {
+        self.writer
+    }
+
+    /// Flushes the line that was just written, syncing it to disk if requested.
+    fn flush_line(&mut self) -> io::Result<()>
+    {
+        self.writer.flush()?;
+        match self.sync
+        {
+            Some(sync) => sync(&mut self.writer),
+            None => Ok(())
+        }
+    }
+
+    /// Writes `element` as a single line of the journal.
+    fn write_line<T: Serialize>(&mut self, element: &T) -> io::Result<()>
+    {
+        self.buffer.clear();
+        serde_json::to_writer(&mut self.buffer, element)?;
+        self.buffer.push(b'\n');
+        self.writer.write_all(&self.buffer)
+    }
+}
+
+impl JournalObserver<fs::File>
+{
+    /// Enables (or disables) syncing the journal file to disk (with `File::sync_data`) after each line,
+    /// so that accepted elements survive a power loss and not only a crash of the process.
+    ///
+    /// Syncing is disabled by default as it makes each accepted element wait for the disk.
+    pub fn with_sync(mut self, sync: bool) -> Self
+    {
+        self.sync = if sync { Some(|file| file.sync_data()) } else { None };
+        self
+    }
+}
+
+impl<T: Serialize, W: io::Write> FrontObserver<T> for JournalObserver<W>
+{
+    fn on_accept(&mut self, element: &T)
+    {
+        if self.error.is_none()
+        {
+            self.error = self.write_line(element).and_then(|()| self.flush_line()).err();
+        }
+    }
+}
+
+/// Kind of error encountered while reading a journal.
+///
+/// **NOTE: This enum is only available when enabling the `pareto_front_journal` feature.**
+#[derive(Debug)]
+pub enum JournalErrorKind
+{
+    /// The journal could not be read or written.
+    Io(io::Error),
+    /// A line could not be converted into an element.
+    Json(serde_json::Error)
+}
+
+/// Error encountered while reading or writing a journal.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_journal` feature.**
+#[derive(Debug)]
+pub struct JournalError
+{
+    /// Line of the journal (starting at 1) at which the error occured, if known.
+    pub line: Option<u64>,
+    /// What went wrong.
+    pub kind: JournalErrorKind
+}
+
+impl fmt::Display for JournalError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        if let Some(line) = self.line
+        {
+            write!(f, "line {line}: ")?;
+        }
+        match &self.kind
+        {
+            JournalErrorKind::Io(error) => write!(f, "{error}"),
+            JournalErrorKind::Json(error) => write!(f, "{error}")
+        }
+    }
+}
+
+impl std::error::Error for JournalError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        match &self.kind
+        {
+            JournalErrorKind::Io(error) => Some(error),
+            JournalErrorKind::Json(error) => Some(error)
+        }
+    }
+}
+
+impl From<io::Error> for JournalError
+{
+    fn from(error: io::Error) -> Self
+    {
+        JournalError { line: None, kind: JournalErrorKind::Io(error) }
+    }
+}
+
+impl<T: DeserializeOwned, D: Dominance<T> + Default, O: FrontObserver<T> + Default> ParetoFront<T, D, O>
+{
+    /// Rebuilds a Pareto front from a JSON Lines journal (such as the one written by a `JournalObserver`),
+    /// pushing its elements in order.
+    ///
+    /// Lines are pushed into the front as they are read, the journal thus never needs to fit in memory.
+    /// Empty lines are skipped and a last line that does not end with a newline is ignored,
+    /// as it is the sign of a write that was interrupted by a crash.
+    /// Errors report the line of the offending element.
+    ///
+    /// The interrupted line is *not* removed from the journal: appending to the journal afterward
+    /// would glue the next element to it and corrupt the journal.
+    /// Use `recover_journal` to rebuild a front from a journal file that will be appended to.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_journal` feature.**
+    pub fn from_journal<R: io::BufRead>(reader: R) -> Result<Self, JournalError>
+    {
+        Self::read_journal(reader).map(|(front, _valid_length)| front)
+    }
+
+    /// Rebuilds a Pareto front from a journal file (see `from_journal`),
+    /// truncating the file to drop a last line interrupted by a crash
+    /// and leaving it positioned at its end so that it can be appended to.
+    ///
+    /// This is the function to call on restart, before attaching a `JournalObserver` writing to `file`.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_journal` feature.**
+    ///
+    /// ```rust,no_run
+    /// # use pareto_front::{Dominate, JournalObserver, ParetoFront};
+    /// # use std::fs::OpenOptions;
+    /// #
+    /// # #[derive(PartialEq, serde::Serialize, serde::Deserialize)]
+    /// # struct ParetoElement
+    /// # {
+    /// #     cost: usize, // to be minimized
+    /// #     quality: f32, // to be maximized
+    /// # }
+    /// #
+    /// # impl Dominate for ParetoElement
+    /// # {
+    /// #     fn dominate(&self, x: &Self) -> bool
+    /// #     {
+    /// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    /// #     }
+    /// # }
+    /// #
+    /// let mut file = OpenOptions::new().read(true).write(true).create(true).truncate(false).open("front.jsonl").unwrap();
+    /// let front: ParetoFront<ParetoElement> = ParetoFront::recover_journal(&mut file).unwrap();
+    /// let mut front = front.with_observer(JournalObserver::new(file));
+    /// front.push(ParetoElement { cost: 35, quality: 0.5 });
+    /// ```
+    pub fn recover_journal(file: &mut fs::File) -> Result<Self, JournalError>
+    {
+        file.seek(SeekFrom::Start(0))?;
+        let (front, valid_length) = Self::read_journal(io::BufReader::new(&*file))?;
+        file.set_len(valid_length)?;
+        file.seek(SeekFrom::End(0))?;
+        Ok(front)
+    }
+
+    /// Rebuilds a Pareto front from a journal (see `from_journal`),
+    /// also returning the number of bytes before the interrupted last line, if any.
+    fn read_journal<R: io::BufRead>(mut reader: R) -> Result<(Self, u64), JournalError>
+    {
+        let mut front = ParetoFront::default();
+        let mut line = String::new();
+        let mut line_number = 0;
+        let mut valid_length = 0;
+        loop
+        {
+            line.clear();
+            line_number += 1;
+            let error_at_line = |kind| JournalError { line: Some(line_number), kind };
+            reader.read_line(&mut line).map_err(|error| error_at_line(JournalErrorKind::Io(error)))?;
+            // stops at the end of the journal or on an interrupted write
+            if !line.ends_with('\n')
+            {
+                return Ok((front, valid_length));
+            }
+            valid_length += line.len() as u64;
+            if line.trim().is_empty()
+            {
+                continue;
+            }
+            let element =
+                serde_json::from_str(&line).map_err(|error| error_at_line(JournalErrorKind::Json(error)))?;
+            front.push(element);
+        }
+    }
+}
+
+impl<T: Serialize, D: Dominance<T>, O: FrontObserver<T>> ParetoFront<T, D, O>
+{
+    /// Writes the elements of the front to `writer` as a JSON Lines journal, one element per line.
+    ///
+    /// The result can be read back with `ParetoFront::from_journal`.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_journal` feature.**
+    pub fn write_journal<W: io::Write>(&self, writer: W) -> io::Result<()>
+    {
+        let mut journal = JournalObserver::new(writer);
+        for element in self.iter()
+        {
+            journal.write_line(element)?;
+        }
+        journal.flush()
+    }
+}
+
+impl<T: Serialize, D: Dominance<T>, W: io::Write> ParetoFront<T, D, JournalObserver<W>>
+{
+    /// Writes the current content of the front to `writer` and makes it the new journal,
+    /// returning the previous journal.
+    ///
+    /// The new journal only contains the elements currently in the front, dropping evicted elements.
+    /// For a crash-safe compaction, write to a temporary file then, once this function returns,
+    /// rename it over the previous journal (later elements are appended to the new journal).
+    ///
+    /// If writing fails, the previous journal is kept.
+    /// If the journal syncs its lines to disk (see `JournalObserver::with_sync`), the new journal is synced before being used
+    /// and later lines are synced as well.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_journal` feature.**
+    pub fn compact_journal(&mut self, mut writer: W) -> io::Result<W>
+    {
+        self.write_journal(&mut writer)?;
+        let sync = self.observer().sync;
+        if let Some(sync) = sync
+        {
+            sync(&mut writer)?;
+        }
+        let new_journal = JournalObserver { sync, ..JournalObserver::new(writer) };
+        let previous_journal = mem::replace(self.observer_mut(), new_journal);
+        Ok(previous_journal.into_inner())
+    }
+}
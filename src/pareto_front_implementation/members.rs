@@ -0,0 +1,520 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Identifier of an element of a `ParetoFront`.
+///
+/// Identifiers stay valid, across mutations of the front, as long as the element stays in the front.
+/// Once the element has left the front, its identifier is invalidated and will not be given to another element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ElementId
+{
+    /// index of the slot of the element
+    slot: u32,
+    /// generation of the slot when the element was inserted
+    generation: u32
+}
+
+/// Position in the history of a `ParetoFront`, obtained with `ParetoFront::checkpoint`.
+///
+/// A checkpoint can only be used with the front it was taken on (not with its clones).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checkpoint
+{
+    /// identifier of the change log of the front, see `ChangeLog::id`
+    log_id: u64,
+    position: u64
+}
+
+/// Changes of a `ParetoFront` between a checkpoint and now.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrontDiff<'a, T>
+{
+    /// Elements that entered the front since the checkpoint and are still in it.
+    pub added: Vec<(ElementId, &'a T)>,
+    /// Identifiers of the elements that were in the front at the checkpoint but have since left it.
+    pub removed: Vec<ElementId>
+}
+
+impl<T> FrontDiff<'_, T>
+{
+    /// Returns `true` if the front did not change since the checkpoint.
+    pub fn is_empty(&self) -> bool
+    {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Event in the history of a front.
+#[derive(Clone, Copy, Debug)]
+enum Change
+{
+    Added(ElementId),
+    Removed(ElementId)
+}
+
+/// Log of the changes of a front, kept once a first checkpoint has been taken.
+#[derive(Debug)]
+struct ChangeLog
+{
+    /// unique to each log (clones included), to recognize the checkpoints taken on it
+    id: u64,
+    /// absolute position of the first change stored
+    first_position: u64,
+    changes: Vec<Change>
+}
+
+/// Identifier given to the next change log created.
+static NEXT_LOG_ID: AtomicU64 = AtomicU64::new(0);
+
+impl ChangeLog
+{
+    /// Creates an empty log, with a new identifier.
+    fn new(first_position: u64, changes: Vec<Change>) -> Self
+    {
+        ChangeLog { id: NEXT_LOG_ID.fetch_add(1, Ordering::Relaxed), first_position, changes }
+    }
+
+    /// Panics if `checkpoint` was not taken on this log or comes after its end.
+    fn check(&self, checkpoint: Checkpoint)
+    {
+        assert_eq!(checkpoint.log_id, self.id, "this checkpoint was not taken on this front");
+        assert!(checkpoint.position <= self.position(),
+                "this checkpoint is ahead of the history of this front");
+    }
+
+    /// Absolute position of the next change to be logged.
+    fn position(&self) -> u64
+    {
+        self.first_position + self.changes.len() as u64
+    }
+}
+
+impl Clone for ChangeLog
+{
+    /// The clone gets a new identifier, as the histories of the two fronts diverge from there.
+    fn clone(&self) -> Self
+    {
+        ChangeLog::new(self.first_position, self.changes.clone())
+    }
+}
+
+/// Entry of the slot map associating identifiers to positions in the storage.
+#[derive(Clone, Copy, Debug)]
+struct Slot
+{
+    /// incremented each time the slot is freed, invalidating the identifiers pointing to it (never wraps)
+    generation: u32,
+    /// position of the element in the storage, `None` if the slot is free
+    position: Option<usize>
+}
+
+/// Identifiers of the elements of a storage, kept once identifiers have been requested.
+#[derive(Clone, Debug, Default)]
+struct Tracking
+{
+    /// identifier of each element, in the same order as the elements
+    ids: Vec<ElementId>,
+    slots: Vec<Slot>,
+    /// indexes of the free slots
+    free_slots: Vec<u32>,
+    /// `None` until the first checkpoint is taken
+    log: Option<ChangeLog>
+}
+
+impl Tracking
+{
+    /// Gives a new identifier to an element inserted at `position`.
+    fn insert(&mut self, position: usize) -> ElementId
+    {
+        let id = match self.free_slots.pop()
+        {
+            Some(slot) =>
+            {
+                self.slots[slot as usize].position = Some(position);
+                ElementId { slot, generation: self.slots[slot as usize].generation }
+            }
+            None =>
+            {
+                self.slots.push(Slot { generation: 0, position: Some(position) });
+                ElementId { slot: (self.slots.len() - 1) as u32, generation: 0 }
+            }
+        };
+        self.ids.push(id);
+        self.log(Change::Added(id));
+        id
+    }
+
+    /// Swaps the identifiers of two elements.
+    fn swap(&mut self, index1: usize, index2: usize)
+    {
+        self.ids.swap(index1, index2);
+        self.slots[self.ids[index1].slot as usize].position = Some(index1);
+        self.slots[self.ids[index2].slot as usize].position = Some(index2);
+    }
+
+    /// Invalidates the identifier of the element at `index`, replacing it with the identifier of the last element.
+    fn swap_remove(&mut self, index: usize)
+    {
+        let id = self.ids.swap_remove(index);
+        self.log(Change::Removed(id));
+
+        // frees the slot of the removed element
+        // a slot whose generation cannot be incremented anymore is retired, as reusing it would revive stale identifiers
+        let slot = &mut self.slots[id.slot as usize];
+        slot.position = None;
+        if let Some(generation) = slot.generation.checked_add(1)
+        {
+            slot.generation = generation;
+            self.free_slots.push(id.slot);
+        }
+
+        // updates the position of the element that took its place
+        if let Some(moved_id) = self.ids.get(index)
+        {
+            self.slots[moved_id.slot as usize].position = Some(index);
+        }
+    }
+
+    /// Logs `change` if a checkpoint has been taken.
+    fn log(&mut self, change: Change)
+    {
+        if let Some(log) = &mut self.log
+        {
+            log.changes.push(change);
+        }
+    }
+}
+
+/// Storage for the elements of a Pareto front.
+///
+/// Once identifiers have been requested, keeps an identifier for each element
+/// (using a slot map to find elements from their identifiers)
+/// and, once a checkpoint has been taken, logs all insertions and removals.
+/// Fronts that never use identifiers thus pay no overhead.
+/// Can also keep, for each element, the number of candidates it dominated (see `ReorderStrategy::CountBased`).
+/// All mutations of the elements of a front go through this type.
+#[derive(Clone, Debug)]
+pub(crate) struct Members<T>
+{
+    elements: Vec<T>,
+    /// `None` until identifiers are first requested
+    tracking: Option<Tracking>,
+    /// number of candidates dominated by each element, `None` unless counting has been enabled
+    kill_counts: Option<Vec<u32>>
+}
+
+impl<T> Members<T>
+{
+    /// Creates an empty storage.
+    pub(crate) fn new() -> Self
+    {
+        Members { elements: Vec::new(), tracking: None, kill_counts: None }
+    }
+
+    /// Creates a storage from a vector.
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    pub(crate) fn from_vec(elements: Vec<T>) -> Self
+    {
+        Members { elements, tracking: None, kill_counts: None }
+    }
+
+    /// Returns the elements as a vector, dropping their identifiers.
+    pub(crate) fn into_vec(self) -> Vec<T>
+    {
+        self.elements
+    }
+
+    /// Returns the elements as a slice.
+    pub(crate) fn as_slice(&self) -> &[T]
+    {
+        &self.elements
+    }
+
+    /// Starts keeping identifiers, giving new identifiers to the elements already in the storage.
+    fn tracking(&mut self) -> &mut Tracking
+    {
+        let nb_elements = self.elements.len();
+        self.tracking.get_or_insert_with(|| {
+                         let mut tracking = Tracking::default();
+                         (0..nb_elements).for_each(|position| {
+                                             tracking.insert(position);
+                                         });
+                         tracking
+                     })
+    }
+
+    /// Returns an iterator over the elements and their identifiers, giving identifiers to the elements if needed.
+    pub(crate) fn iter_with_ids(&mut self) -> impl Iterator<Item = (ElementId, &T)>
+    {
+        self.tracking();
+        self.iter_tracked()
+    }
+
+    /// Returns an iterator over the elements and their identifiers, empty if identifiers are not kept.
+    fn iter_tracked(&self) -> impl Iterator<Item = (ElementId, &T)>
+    {
+        let ids = self.tracking.as_ref().map(|tracking| tracking.ids.as_slice()).unwrap_or_default();
+        ids.iter().copied().zip(self.elements.iter())
+    }
+
+    /// Returns the position of the element with identifier `id`, if it is still in the storage.
+    pub(crate) fn position(&self, id: ElementId) -> Option<usize>
+    {
+        self.tracking
+            .as_ref()?
+            .slots
+            .get(id.slot as usize)
+            .filter(|slot| slot.generation == id.generation)
+            .and_then(|slot| slot.position)
+    }
+
+    /// Returns the element with identifier `id`, if it is still in the storage.
+    pub(crate) fn get(&self, id: ElementId) -> Option<&T>
+    {
+        self.position(id).map(|position| &self.elements[position])
+    }
+
+    /// Starts or stops counting the number of candidates dominated by each element.
+    pub(crate) fn count_kills(&mut self, is_counting: bool)
+    {
+        match (is_counting, &self.kill_counts)
+        {
+            (true, None) => self.kill_counts = Some(vec![0; self.elements.len()]),
+            (false, Some(_)) => self.kill_counts = None,
+            _ => ()
+        }
+    }
+
+    /// Records that the element at `index` dominated a candidate,
+    /// moving it up such that elements stay sorted by decreasing number of candidates dominated.
+    ///
+    /// Does nothing if counting is not enabled.
+    pub(crate) fn record_kill(&mut self, index: usize)
+    {
+        let Some(kill_counts) = &mut self.kill_counts
+        else
+        {
+            return;
+        };
+        kill_counts[index] = kill_counts[index].saturating_add(1);
+        let mut index = index;
+        while (index > 0) && (kill_counts[index] > kill_counts[index - 1])
+        {
+            kill_counts.swap(index, index - 1);
+            self.elements.swap(index, index - 1);
+            if let Some(tracking) = &mut self.tracking
+            {
+                tracking.swap(index, index - 1);
+            }
+            index -= 1;
+        }
+    }
+
+    /// Returns `true` if identifiers or kill counts are kept alongside the elements.
+    #[inline(always)]
+    fn has_side_data(&self) -> bool
+    {
+        self.tracking.is_some() || self.kill_counts.is_some()
+    }
+
+    /// Updates identifiers and kill counts for an element added at the end of the storage.
+    ///
+    /// Kept out of line, as `swap_side_data` and `swap_remove_side_data`,
+    /// so that fronts without side data pay as little as possible for it.
+    #[cold]
+    fn push_side_data(&mut self)
+    {
+        if let Some(tracking) = &mut self.tracking
+        {
+            tracking.insert(self.elements.len());
+        }
+        if let Some(kill_counts) = &mut self.kill_counts
+        {
+            kill_counts.push(0);
+        }
+    }
+
+    /// Swaps the identifiers and kill counts of two elements.
+    #[cold]
+    fn swap_side_data(&mut self, index1: usize, index2: usize)
+    {
+        if let Some(tracking) = &mut self.tracking
+        {
+            tracking.swap(index1, index2);
+        }
+        if let Some(kill_counts) = &mut self.kill_counts
+        {
+            kill_counts.swap(index1, index2);
+        }
+    }
+
+    /// Removes the identifier and kill count of an element, replacing them with the ones of the last element.
+    #[cold]
+    fn swap_remove_side_data(&mut self, index: usize)
+    {
+        if let Some(tracking) = &mut self.tracking
+        {
+            tracking.swap_remove(index);
+        }
+        if let Some(kill_counts) = &mut self.kill_counts
+        {
+            kill_counts.swap_remove(index);
+        }
+    }
+
+    /// Adds an element at the end of the storage.
+    #[inline]
+    pub(crate) fn push(&mut self, element: T)
+    {
+        if self.has_side_data()
+        {
+            self.push_side_data();
+        }
+        self.elements.push(element);
+    }
+
+    /// Adds an element at the end of the storage, returning its new identifier.
+    pub(crate) fn push_with_id(&mut self, element: T) -> ElementId
+    {
+        self.tracking();
+        self.push_side_data();
+        self.elements.push(element);
+        self.tracking
+            .as_ref()
+            .and_then(|tracking| tracking.ids.last().copied())
+            .expect("identifiers are kept")
+    }
+
+    /// Swaps two elements.
+    #[inline]
+    pub(crate) fn swap(&mut self, index1: usize, index2: usize)
+    {
+        self.elements.swap(index1, index2);
+        if self.has_side_data()
+        {
+            self.swap_side_data(index1, index2);
+        }
+    }
+
+    /// Removes an element, replacing it with the last element.
+    ///
+    /// When kill counts are kept, the following elements are shifted instead, in `O(n)`,
+    /// so that the elements stay sorted by kill count (see `ReorderStrategy::CountBased`).
+    #[inline]
+    pub(crate) fn remove(&mut self, mut index: usize) -> T
+    {
+        if self.has_side_data()
+        {
+            if self.kill_counts.is_some()
+            {
+                index = self.shift_to_end(index);
+            }
+            self.swap_remove_side_data(index);
+        }
+        self.elements.swap_remove(index)
+    }
+
+    /// Moves the element at `index` to the end of the storage, shifting the following elements, and returns its new index.
+    #[cold]
+    fn shift_to_end(&mut self, index: usize) -> usize
+    {
+        // a sequence of swaps (rather than a rotation) keeps the identifiers and kill counts of the elements up to date
+        let last = self.elements.len() - 1;
+        for i in index..last
+        {
+            self.swap(i, i + 1);
+        }
+        last
+    }
+
+    /// Keeps only the elements for which `predicate` returns `true`.
+    ///
+    /// This operation might *not* preserve the ordering of the elements.
+    pub(crate) fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F)
+    {
+        let mut index = 0;
+        while index < self.elements.len()
+        {
+            if predicate(&self.elements[index])
+            {
+                index += 1;
+            }
+            else
+            {
+                self.remove(index);
+            }
+        }
+    }
+
+    /// Returns a checkpoint corresponding to the current state, starting to log changes if needed.
+    pub(crate) fn checkpoint(&mut self) -> Checkpoint
+    {
+        let log = self.tracking().log.get_or_insert_with(|| ChangeLog::new(0, Vec::new()));
+        Checkpoint { log_id: log.id, position: log.position() }
+    }
+
+    /// Returns the changes that happened since `checkpoint`.
+    ///
+    /// Panics if no checkpoint was taken, if `checkpoint` was taken on another front
+    /// or if the changes since `checkpoint` have been forgotten.
+    pub(crate) fn changes_since(&self, checkpoint: Checkpoint) -> FrontDiff<'_, T>
+    {
+        let log = self.tracking
+                      .as_ref()
+                      .and_then(|tracking| tracking.log.as_ref())
+                      .expect("no checkpoint was taken on this front");
+        log.check(checkpoint);
+        assert!(checkpoint.position >= log.first_position,
+                "the changes since this checkpoint have been forgotten");
+        let start = (checkpoint.position - log.first_position) as usize;
+
+        // elements added since the checkpoint that are still in the front and elements removed that existed before
+        let mut added = HashSet::new();
+        let mut removed = Vec::new();
+        for change in &log.changes[start..]
+        {
+            match *change
+            {
+                Change::Added(id) =>
+                {
+                    added.insert(id);
+                }
+                Change::Removed(id) =>
+                {
+                    if !added.remove(&id)
+                    {
+                        removed.push(id);
+                    }
+                }
+            }
+        }
+
+        let added = self.iter_tracked().filter(|(id, _)| added.contains(id)).collect();
+        FrontDiff { added, removed }
+    }
+
+    /// Forgets the changes that happened before `checkpoint`, freeing memory.
+    ///
+    /// Panics if `checkpoint` was taken on another front.
+    pub(crate) fn forget_changes_before(&mut self, checkpoint: Checkpoint)
+    {
+        if let Some(log) = self.tracking.as_mut().and_then(|tracking| tracking.log.as_mut())
+        {
+            log.check(checkpoint);
+            let nb_forgotten =
+                checkpoint.position.saturating_sub(log.first_position).min(log.changes.len() as u64);
+            log.changes.drain(..nb_forgotten as usize);
+            log.first_position += nb_forgotten;
+        }
+    }
+}
+
+/// Members are serialized as a plain sequence of elements, identifiers and change log are not kept.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Members<T>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        self.elements.serialize(serializer)
+    }
+}
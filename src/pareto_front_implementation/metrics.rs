@@ -0,0 +1,101 @@
+//! Metrics to compare Pareto fronts, typically obtained with different algorithms.
+use crate::{Dominance, FrontObserver, ParetoFront};
+
+/// Relation between two Pareto fronts, as defined by set dominance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrontRelation
+{
+    /// All elements of the second front are dominated by, or equal to, an element of the first front
+    /// but the converse is not true.
+    Better,
+    /// All elements of the first front are dominated by, or equal to, an element of the second front
+    /// but the converse is not true.
+    Worse,
+    /// Both fronts contain the same elements.
+    Equal,
+    /// Each front contains an element that is neither dominated by, nor equal to, any element of the other front.
+    Incomparable
+}
+
+/// Returns `true` if `x` is dominated by, or equal to, an element of `front`.
+fn is_covered<T: PartialEq, D: Dominance<T>, O: FrontObserver<T>>(front: &ParetoFront<T, D, O>, x: &T)
+                                                                  -> bool
+{
+    front.iter().any(|element| (element == x) || front.comparator().dominate(element, x))
+}
+
+/// Returns the coverage `C(a, b)`: the fraction of the elements of `b`
+/// that are dominated by, or equal to, an element of `a`.
+///
+/// The dominance relation of `a` is used and an empty `b` has a coverage of `0`.
+/// Note that `C(a, b)` is not equal to `1 - C(b, a)` in general.
+///
+/// This operation has `O(n*m)` complexity
+/// where `n` and `m` are the number of elements in `a` and `b`.
+pub fn coverage<T, D, Oa, Ob>(a: &ParetoFront<T, D, Oa>, b: &ParetoFront<T, D, Ob>) -> f64
+    where T: PartialEq,
+          D: Dominance<T>,
+          Oa: FrontObserver<T>,
+          Ob: FrontObserver<T>
+{
+    if b.is_empty()
+    {
+        return 0.;
+    }
+    let nb_covered = b.iter().filter(|x| is_covered(a, x)).count();
+    nb_covered as f64 / b.len() as f64
+}
+
+/// Compares two Pareto fronts using set dominance.
+///
+/// `a` is better than `b` if all elements of `b` are dominated by, or equal to, an element of `a`
+/// while the converse is not true.
+///
+/// This operation has `O(n*m)` complexity
+/// where `n` and `m` are the number of elements in `a` and `b`.
+///
+/// ```rust
+/// # use pareto_front::{Dominate, ParetoFront};
+/// # use pareto_front::metrics::{compare, FrontRelation};
+/// #
+/// # #[derive(PartialEq)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// let mut a = ParetoFront::new();
+/// a.push(ParetoElement { cost: 35, quality: 0.5 });
+/// a.push(ParetoElement { cost: 5, quality: 0.25 });
+///
+/// let mut b = ParetoFront::new();
+/// b.push(ParetoElement { cost: 35, quality: 0.25 });
+///
+/// assert_eq!(compare(&a, &b), FrontRelation::Better);
+/// assert_eq!(compare(&b, &a), FrontRelation::Worse);
+/// ```
+pub fn compare<T, D, Oa, Ob>(a: &ParetoFront<T, D, Oa>, b: &ParetoFront<T, D, Ob>) -> FrontRelation
+    where T: PartialEq,
+          D: Dominance<T>,
+          Oa: FrontObserver<T>,
+          Ob: FrontObserver<T>
+{
+    let a_covers_b = b.iter().all(|x| is_covered(a, x));
+    let b_covers_a = a.iter().all(|x| is_covered(b, x));
+    match (a_covers_b, b_covers_a)
+    {
+        (true, true) => FrontRelation::Equal,
+        (true, false) => FrontRelation::Better,
+        (false, true) => FrontRelation::Worse,
+        (false, false) => FrontRelation::Incomparable
+    }
+}
@@ -1,8 +1,55 @@
 mod dominate;
 pub use dominate::Dominate;
+mod dominance;
+pub use dominance::{Dominance, NaturalDominance};
+mod objectives;
+pub use objectives::Objectives;
+pub mod nan_safe;
+pub use nan_safe::{NanError, NanPolicy};
+#[cfg(feature = "pareto_front_blanket")]
+mod blanket_dominate;
+#[cfg(feature = "pareto_front_blanket")]
+pub use blanket_dominate::{Forward, Maximize};
+mod members;
+pub use members::{Checkpoint, ElementId, FrontDiff};
+mod observer;
+pub use observer::{FrontObserver, NoObserver};
+mod reorder_strategy;
+pub use reorder_strategy::ReorderStrategy;
+mod stats;
+#[cfg(feature = "pareto_front_stats")]
+pub use stats::FrontStats;
 mod pareto_front;
 pub use self::pareto_front::ParetoFront;
+mod ordered_pareto_front;
+pub use ordered_pareto_front::OrderedParetoFront;
+#[cfg(feature = "pareto_front_concurrent")]
+mod poison;
 #[cfg(feature = "pareto_front_concurrent")]
 mod concurrent_pareto_front;
 #[cfg(feature = "pareto_front_concurrent")]
 pub use concurrent_pareto_front::ConcurrentParetoFront;
+#[cfg(feature = "pareto_front_concurrent")]
+mod shared_pareto_front;
+#[cfg(feature = "pareto_front_concurrent")]
+pub use shared_pareto_front::SharedParetoFront;
+#[cfg(feature = "pareto_front_concurrent")]
+mod pareto_front_actor;
+#[cfg(feature = "pareto_front_concurrent")]
+pub use pareto_front_actor::{ParetoFrontActor, ParetoFrontHandle};
+#[cfg(feature = "pareto_front_csv")]
+mod csv_io;
+#[cfg(feature = "pareto_front_csv")]
+pub use csv_io::{CsvColumn, CsvError, CsvErrorKind, CsvOptions};
+#[cfg(feature = "pareto_front_journal")]
+mod journal;
+#[cfg(feature = "pareto_front_journal")]
+pub use journal::{JournalError, JournalErrorKind, JournalObserver};
+#[cfg(feature = "pareto_front_binary")]
+mod binary;
+#[cfg(feature = "pareto_front_binary")]
+pub use binary::BinaryError;
+pub mod metrics;
+mod reference_point;
+pub use reference_point::{ReferencePoint, ReferencePointFront};
+pub mod relaxed_dominance;
@@ -0,0 +1,187 @@
+//! Floating point dominance that does not let NaN objectives corrupt the front.
+//!
+//! With a naive floating point comparison, an element with a NaN objective is never dominated and never dominates,
+//! meaning that it stays in the front forever.
+//! The helpers defined here treat NaN as the worst possible value
+//! and let the `Objectives::NAN_POLICY` of a type decide what `ParetoFront::try_push` does with such elements.
+use crate::{Dominance, Dominate, Objectives};
+use std::cmp::Ordering;
+use std::fmt;
+
+/// What to do with elements that have a NaN objective.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NanPolicy
+{
+    /// NaN objectives are considered worse than any other value.
+    Worst,
+    /// Elements with a NaN objective are refused by `ParetoFront::try_push`
+    /// (they are considered worst if they end up in a front anyway).
+    Reject,
+    /// Elements with a NaN objective cause a panic in debug mode
+    /// (they are refused by `ParetoFront::try_push` in release mode).
+    Panic
+}
+
+/// Error returned when trying to push an element with a NaN objective into a front.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NanError<T>
+{
+    /// The rejected element.
+    pub element: T,
+    /// Index of the first NaN objective of the element.
+    pub objective: usize
+}
+
+impl<T> fmt::Display for NanError<T>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "element rejected as its objective number {} is NaN", self.objective)
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for NanError<T> {}
+
+/// Returns the index of the first NaN objective of `x`, if any.
+pub fn find_nan<T: Objectives + ?Sized>(x: &T) -> Option<usize>
+{
+    (0..x.nb_objectives()).find(|&index| x.objective(index).is_nan())
+}
+
+/// Compares two objectives, NaN being considered worse than any other value (and equal to itself).
+pub fn nan_safe_cmp(x: f64, y: f64) -> Ordering
+{
+    match (x.is_nan(), y.is_nan())
+    {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => x.partial_cmp(&y).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Returns `true` if `x` is smaller or equal to `y` on all objectives and strictly smaller on at least one,
+/// NaN objectives being considered worse than any other value.
+///
+/// Panics in debug mode if one of the objectives is NaN and the `NAN_POLICY` of `T` is `NanPolicy::Panic`.
+///
+/// ```rust
+/// # use pareto_front::{Dominate, Objectives, nan_safe::nan_safe_dominate};
+/// #
+/// /// element whose objectives come from a simulation
+/// struct Simulation
+/// {
+///     objectives: [f64; 2]
+/// }
+///
+/// impl Objectives for Simulation
+/// {
+///     fn nb_objectives(&self) -> usize
+///     {
+///         2
+///     }
+///
+///     fn objective(&self, index: usize) -> f64
+///     {
+///         self.objectives[index]
+///     }
+/// }
+///
+/// impl Dominate for Simulation
+/// {
+///     fn dominate(&self, x: &Self) -> bool
+///     {
+///         nan_safe_dominate(self, x)
+///     }
+/// }
+///
+/// let x = Simulation { objectives: [1., 2.] };
+/// let y = Simulation { objectives: [1., f64::NAN] };
+/// assert!(x.dominate(&y));
+/// ```
+pub fn nan_safe_dominate<T: Objectives + ?Sized>(x: &T, y: &T) -> bool
+{
+    let mut is_strictly_better = false;
+    for index in 0..x.nb_objectives()
+    {
+        let (xo, yo) = (x.objective(index), y.objective(index));
+        if T::NAN_POLICY == NanPolicy::Panic
+        {
+            debug_assert!(!xo.is_nan() && !yo.is_nan(), "objective number {} is NaN", index);
+        }
+        match nan_safe_cmp(xo, yo)
+        {
+            Ordering::Less => is_strictly_better = true,
+            Ordering::Equal => (),
+            Ordering::Greater => return false
+        }
+    }
+    is_strictly_better
+}
+
+/// Comparator applying `nan_safe_dominate` to the objectives of the elements.
+///
+/// Can be given to `ParetoFront::with_comparator` for types that implement `Objectives` but not `Dominate`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NanSafeDominance;
+
+impl<T: Objectives> Dominance<T> for NanSafeDominance
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        nan_safe_dominate(x, y)
+    }
+}
+
+/// defines a wrapper that gives an explicit `NanPolicy` to a type implementing `Objectives`
+macro_rules! nan_policy_wrapper {
+    ($(#[$doc:meta])* $name:ident, $policy:expr) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name<T>(pub T);
+
+        impl<T: Objectives> Objectives for $name<T>
+        {
+            const NAN_POLICY: NanPolicy = $policy;
+
+            fn nb_objectives(&self) -> usize
+            {
+                self.0.nb_objectives()
+            }
+
+            fn objective(&self, index: usize) -> f64
+            {
+                self.0.objective(index)
+            }
+        }
+
+        impl<T: Objectives> Dominate for $name<T>
+        {
+            fn dominate(&self, x: &Self) -> bool
+            {
+                nan_safe_dominate(self, x)
+            }
+        }
+    };
+}
+
+nan_policy_wrapper!(
+    /// Wraps an element such that NaN objectives are considered worse than any other value.
+    NanWorst,
+    NanPolicy::Worst
+);
+
+nan_policy_wrapper!(
+    /// Wraps an element such that it is refused by `ParetoFront::try_push` if it has a NaN objective.
+    NanReject,
+    NanPolicy::Reject
+);
+
+nan_policy_wrapper!(
+    /// Wraps an element such that a NaN objective triggers a panic, in debug mode, when it is compared or pushed.
+    NanPanic,
+    NanPolicy::Panic
+);
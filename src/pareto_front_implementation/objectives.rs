@@ -0,0 +1,50 @@
+use crate::NanPolicy;
+
+/// Gives access to the numerical objectives of an element.
+///
+/// By convention, all objectives are to be *minimized*:
+/// an objective that should be maximized is expected to be returned negated.
+///
+/// ```rust
+/// # use pareto_front::Objectives;
+/// #
+/// /// type that will be pushed in the Pareto front
+/// struct ParetoElement
+/// {
+///     cost: usize, // to be minimized
+///     quality: f32, // to be maximized
+/// }
+///
+/// /// exposes the objectives of the element, quality being negated as it is to be maximized
+/// impl Objectives for ParetoElement
+/// {
+///     fn nb_objectives(&self) -> usize
+///     {
+///         2
+///     }
+///
+///     fn objective(&self, index: usize) -> f64
+///     {
+///         match index
+///         {
+///             0 => self.cost as f64,
+///             _ => -self.quality as f64
+///         }
+///     }
+/// }
+/// ```
+pub trait Objectives
+{
+    /// What `ParetoFront::try_push` and `nan_safe_dominate` should do with elements that have a NaN objective.
+    ///
+    /// Defaults to `NanPolicy::Reject`.
+    const NAN_POLICY: NanPolicy = NanPolicy::Reject;
+
+    /// Returns the number of objectives of the element.
+    fn nb_objectives(&self) -> usize;
+
+    /// Returns the value of the objective number `index`, lower values being better.
+    ///
+    /// `index` is expected to be smaller than `nb_objectives`.
+    fn objective(&self, index: usize) -> f64;
+}
@@ -0,0 +1,111 @@
+/// Gets notified of the elements entering and leaving a Pareto front.
+///
+/// All methods default to doing nothing, one only needs to implement the ones of interest.
+///
+/// ```rust
+/// # use pareto_front::{Dominate, FrontObserver, ParetoFront};
+/// #
+/// # #[derive(PartialEq)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// /// counts the elements that got evicted from the front
+/// #[derive(Default)]
+/// struct EvictionCounter
+/// {
+///     nb_evictions: usize
+/// }
+///
+/// impl<T> FrontObserver<T> for EvictionCounter
+/// {
+///     fn on_evict(&mut self, _element: &T)
+///     {
+///         self.nb_evictions += 1;
+///     }
+/// }
+///
+/// let mut front = ParetoFront::new().with_observer(EvictionCounter::default());
+/// front.push(ParetoElement { cost: 35, quality: 0.5 });
+/// front.push(ParetoElement { cost: 5, quality: 0.25 });
+/// front.push(ParetoElement { cost: 1, quality: 0.75 }); // dominates both previous elements
+/// assert_eq!(front.observer().nb_evictions, 2);
+/// ```
+pub trait FrontObserver<T>
+{
+    /// Called when `element` enters the front.
+    fn on_accept(&mut self, _element: &T) {}
+
+    /// Called when `element` is refused by the front as it is dominated.
+    fn on_reject(&mut self, _element: &T) {}
+
+    /// Called when `element`, previously in the front, is removed from it.
+    fn on_evict(&mut self, _element: &T) {}
+}
+
+/// Observer that does nothing, the default observer of all fronts.
+///
+/// Calls to its methods are optimized away, meaning that a front without an observer pays no overhead.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NoObserver;
+
+impl<T> FrontObserver<T> for NoObserver
+{
+    #[inline(always)]
+    fn on_accept(&mut self, _element: &T) {}
+
+    #[inline(always)]
+    fn on_reject(&mut self, _element: &T) {}
+
+    #[inline(always)]
+    fn on_evict(&mut self, _element: &T) {}
+}
+
+/// Adapter used when removing dominated elements from a front that is being merged into another:
+/// elements evicted from the merged front never enter the receiving front.
+pub(crate) struct MergedSide<'a, O>
+{
+    pub(crate) observer: &'a mut O,
+    /// `true` if the elements of the merged front were already reported as accepted
+    /// (and should thus be reported as evicted rather than rejected)
+    pub(crate) already_accepted: bool
+}
+
+impl<T, O: FrontObserver<T>> FrontObserver<T> for MergedSide<'_, O>
+{
+    fn on_accept(&mut self, element: &T)
+    {
+        if !self.already_accepted
+        {
+            self.observer.on_accept(element);
+        }
+    }
+
+    fn on_reject(&mut self, element: &T)
+    {
+        self.observer.on_reject(element);
+    }
+
+    fn on_evict(&mut self, element: &T)
+    {
+        if self.already_accepted
+        {
+            self.observer.on_evict(element);
+        }
+        else
+        {
+            self.observer.on_reject(element);
+        }
+    }
+}
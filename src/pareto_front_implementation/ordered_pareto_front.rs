@@ -0,0 +1,234 @@
+use crate::{Dominance, Dominate, NaturalDominance};
+use std::slice::Iter;
+use std::iter::FromIterator;
+
+/// Represents a Pareto front that keeps its elements in insertion order.
+///
+/// Unlike `ParetoFront`, which moves the elements that dominate many candidates to the top of the front
+/// and removes elements by swapping them with the last element,
+/// this front never reorders its elements: iterating on it returns the elements in the order in which they were pushed.
+///
+/// Insertion still stops as soon as an element dominating the candidate is found
+/// but, without the reordering, such an element tends to be found later in the front
+/// and removing elements costs `O(n)`.
+/// Building a front is thus expected to be slower than with `ParetoFront`, by an amount that depends on the data
+/// (the `ordered_insert_5000` group of `benches/push.rs` compares both fronts on a given distribution).
+///
+/// The comparator is *not* serialized, a deserialized front uses the default value of its comparator.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderedParetoFront<T, D = NaturalDominance>
+{
+    front: Vec<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dominance: D
+}
+
+impl<T: Dominate> OrderedParetoFront<T>
+{
+    /// Constructs a new, empty, ordered Pareto front.
+    pub fn new() -> Self
+    {
+        OrderedParetoFront { front: Vec::new(), dominance: NaturalDominance }
+    }
+}
+
+impl<T, D: Dominance<T>> OrderedParetoFront<T, D>
+{
+    /// Constructs a new, empty, ordered Pareto front using `dominance` as its dominance relation.
+    pub fn with_comparator(dominance: D) -> Self
+    {
+        OrderedParetoFront { front: Vec::new(), dominance }
+    }
+
+    /// Returns the comparator used as dominance relation by the front.
+    pub fn comparator(&self) -> &D
+    {
+        &self.dominance
+    }
+
+    /// Removes all elements in the front that are dominated by `new_element`,
+    /// starting at index `index_start` and preserving the order of the remaining elements.
+    fn _remove_dominated_starting_at(&mut self, new_element: &T, index_start: usize)
+    {
+        let mut index = 0;
+        let dominance = &self.dominance;
+        self.front.retain(|element| {
+                      let is_kept = (index < index_start) || !dominance.dominate(new_element, element);
+                      index += 1;
+                      is_kept
+                  });
+    }
+
+    /// Returns `true` if at least one element on the Pareto front dominates `new_element`.
+    ///
+    /// This operation has `O(n)` complexity (where `n` is the number of elements currently in the Pareto front).
+    pub fn dominate(&self, new_element: &T) -> bool
+    {
+        self.front.iter().any(|element| self.dominance.dominate(element, new_element))
+    }
+
+    /// Adds `new_element` at the end of the Pareto front.
+    /// Returns `true` if the element is now in the Pareto front.
+    /// Returns `false` if the element was dominated and, thus, not added to the front.
+    ///
+    /// This operation has `O(n)` complexity (where `n` is the number of elements currently in the Pareto front)
+    /// and preserves the ordering of the elements in the front.
+    ///
+    /// ```rust
+    /// # use pareto_front::{Dominate, OrderedParetoFront};
+    /// #
+    /// # #[derive(PartialEq, Debug)]
+    /// # struct ParetoElement
+    /// # {
+    /// #     cost: usize, // to be minimized
+    /// #     quality: f32, // to be maximized
+    /// # }
+    /// #
+    /// # impl Dominate for ParetoElement
+    /// # {
+    /// #     fn dominate(&self, x: &Self) -> bool
+    /// #     {
+    /// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    /// #     }
+    /// # }
+    /// #
+    /// let mut front = OrderedParetoFront::new();
+    /// front.push(ParetoElement { cost: 35, quality: 0.5 });
+    /// front.push(ParetoElement { cost: 5, quality: 0.25 });
+    /// front.push(ParetoElement { cost: 50, quality: 0.75 });
+    /// front.push(ParetoElement { cost: 1, quality: 0.25 }); // dominates the second element
+    ///
+    /// // elements are kept in insertion order
+    /// let costs: Vec<_> = front.iter().map(|x| x.cost).collect();
+    /// assert_eq!(costs, vec![35, 50, 1]);
+    /// ```
+    pub fn push(&mut self, new_element: T) -> bool
+    {
+        // the early stopping below relies on the transitivity of the dominance relation
+        // (an element dominating `new_element` would also dominate any element dominated by `new_element`)
+        if !self.dominance.is_transitive()
+        {
+            if self.dominate(&new_element)
+            {
+                return false;
+            }
+            self._remove_dominated_starting_at(&new_element, 0);
+            self.front.push(new_element);
+            return true;
+        }
+
+        // for all elements of the pareto front, check whether they are dominated or dominate `new_element`
+        for (index, element) in self.front.iter().enumerate()
+        {
+            if self.dominance.dominate(element, &new_element)
+            {
+                // `new_element` is dominated by `element`, it is thus not part of the Pareto front
+                return false;
+            }
+            else if self.dominance.dominate(&new_element, element)
+            {
+                // `new_element` dominates `element`, it is thus part of the Pareto front
+                // removes any further element that are dominated
+                self._remove_dominated_starting_at(&new_element, index);
+                break;
+            }
+        }
+
+        // `new_element` has not been dominated, it is thus part of the Pareto front
+        self.front.push(new_element);
+        true
+    }
+
+    /// Adds the content of `pareto_front` to the Pareto front.
+    /// The elements of `pareto_front` that are not dominated are added, in order, after the elements of `self`.
+    ///
+    /// This operation has `O(n*m)` complexity
+    /// where `n` is the number of elements in `self`
+    /// and `m` is the number of elements in `pareto_front`.
+    pub fn merge(&mut self, pareto_front: OrderedParetoFront<T, D>)
+    {
+        self.extend(pareto_front.front);
+    }
+
+    /// Extracts a slice containing the entire Pareto front, in insertion order.
+    pub fn as_slice(&self) -> &[T]
+    {
+        self.front.as_slice()
+    }
+
+    /// Returns the number of elements currently in the Pareto front.
+    pub fn len(&self) -> usize
+    {
+        self.front.len()
+    }
+
+    /// Returns `true` if the Pareto front contains no elements.
+    pub fn is_empty(&self) -> bool
+    {
+        self.front.is_empty()
+    }
+
+    /// Returns an iterator over the Pareto front, in insertion order.
+    pub fn iter(&self) -> Iter<'_, T>
+    {
+        self.front.iter()
+    }
+
+    // no `iter_mut` as the mutation could invalidate the front
+}
+
+impl<T, D: Dominance<T> + Default> Default for OrderedParetoFront<T, D>
+{
+    /// Default value.
+    fn default() -> Self
+    {
+        // Manually implemented so as to not require `T` to implement `Default`.
+        Self::with_comparator(D::default())
+    }
+}
+
+impl<T, D> From<OrderedParetoFront<T, D>> for Vec<T>
+{
+    /// Converts the Pareto front into a vector, in insertion order.
+    /// This operation is free as the underlying datastructure is a vector.
+    fn from(front: OrderedParetoFront<T, D>) -> Vec<T>
+    {
+        front.front
+    }
+}
+
+impl<T, D> IntoIterator for OrderedParetoFront<T, D>
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    /// Creates an iterator from an `OrderedParetoFront`, in insertion order.
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.front.into_iter()
+    }
+}
+
+impl<T, D: Dominance<T> + Default> FromIterator<T> for OrderedParetoFront<T, D>
+{
+    /// Implements the `FromIterator` trait to enable the collection of an iterator into an `OrderedParetoFront`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+    {
+        let mut front = OrderedParetoFront::default();
+        front.extend(iter);
+        front
+    }
+}
+
+impl<T, D: Dominance<T>> Extend<T> for OrderedParetoFront<T, D>
+{
+    /// Implements the `Extend` trait to extend an `OrderedParetoFront` with the content of an iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+    {
+        for x in iter
+        {
+            self.push(x);
+        }
+    }
+}
@@ -1,13 +1,40 @@
-use crate::Dominate;
+use crate::{Dominance, Dominate, NanError, NanPolicy, NaturalDominance, Objectives};
+use crate::nan_safe::find_nan;
+use super::members::{Checkpoint, ElementId, FrontDiff, Members};
+use super::observer::{FrontObserver, MergedSide, NoObserver};
+use super::reorder_strategy::ReorderStrategy;
+use super::stats::StatsRecorder;
+#[cfg(feature = "pareto_front_stats")]
+use super::stats::FrontStats;
 use std::slice::Iter;
 use std::iter::FromIterator;
+#[cfg(feature = "pareto_front_rayon")]
+use rayon::iter::{FromParallelIterator, IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator,
+              ParallelExtend, ParallelIterator};
 
 /// Represents a Pareto front.
+///
+/// The dominance relation used by the front is given by the comparator `D`
+/// which defaults to the `Dominate` implementation of the elements.
+///
+/// The front can also notify an observer `O` (see `FrontObserver`) of the elements entering and leaving it,
+/// by default no observer is attached.
+///
+/// The comparator, observer and reorder strategy are *not* serialized, a deserialized front uses their default values.
+/// Deserialization checks that no element dominates another one (see `ParetoFront::deserialize_strict`).
 #[derive(Clone, Debug)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct ParetoFront<T: Dominate>
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ParetoFront<T, D = NaturalDominance, O = NoObserver>
 {
-    front: Vec<T>
+    front: Members<T>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    dominance: D,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    observer: O,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    reorder_strategy: ReorderStrategy,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    stats: StatsRecorder
 }
 
 impl<T: Dominate> ParetoFront<T>
@@ -15,18 +42,144 @@ impl<T: Dominate> ParetoFront<T>
     /// Constructs a new, empty, Pareto front.
     pub fn new() -> Self
     {
-        ParetoFront { front: Vec::new() }
+        ParetoFront { front: Members::new(),
+                      dominance: NaturalDominance,
+                      observer: NoObserver,
+                      reorder_strategy: ReorderStrategy::default(),
+                      stats: StatsRecorder::new() }
+    }
+}
+
+impl<T, D: Dominance<T>> ParetoFront<T, D>
+{
+    /// Constructs a new, empty, Pareto front using `dominance` as its dominance relation.
+    ///
+    /// The comparator can be any type implementing the `Dominance` trait, including closures.
+    /// This makes it possible to build several fronts, on different objectives, out of the same type.
+    ///
+    /// ```rust
+    /// # use pareto_front::ParetoFront;
+    /// #
+    /// /// evaluation record, does not need to implement `Dominate`
+    /// struct Evaluation
+    /// {
+    ///     cost: usize, // to be minimized
+    ///     latency: f32, // to be minimized
+    ///     accuracy: f32, // to be maximized
+    /// }
+    ///
+    /// // one front for each trade-off of interest
+    /// let mut cost_latency_front = ParetoFront::with_comparator(|x: &&Evaluation, y: &&Evaluation| {
+    ///     (x.cost <= y.cost) && (x.latency <= y.latency) && ((x.cost < y.cost) || (x.latency < y.latency))
+    /// });
+    /// let mut cost_accuracy_front = ParetoFront::with_comparator(|x: &&Evaluation, y: &&Evaluation| {
+    ///     (x.cost <= y.cost) && (x.accuracy >= y.accuracy) && ((x.cost < y.cost) || (x.accuracy > y.accuracy))
+    /// });
+    ///
+    /// // fills both fronts with the same evaluations
+    /// let evaluations = vec![Evaluation { cost: 35, latency: 0.5, accuracy: 0.9 },
+    ///                        Evaluation { cost: 5, latency: 0.7, accuracy: 0.95 }];
+    /// for evaluation in evaluations.iter()
+    /// {
+    ///     cost_latency_front.push(evaluation);
+    ///     cost_accuracy_front.push(evaluation);
+    /// }
+    /// assert_eq!(cost_latency_front.len(), 2);
+    /// assert_eq!(cost_accuracy_front.len(), 1);
+    /// ```
+    pub fn with_comparator(dominance: D) -> Self
+    {
+        ParetoFront { front: Members::new(),
+                      dominance,
+                      observer: NoObserver,
+                      reorder_strategy: ReorderStrategy::default(),
+                      stats: StatsRecorder::new() }
+    }
+}
+
+impl<T, D: Dominance<T>, O: FrontObserver<T>> ParetoFront<T, D, O>
+{
+    /// Attaches `observer` to the front, it will be notified of all elements entering and leaving the front from now on.
+    ///
+    /// Elements already in the front are kept but not reported to the observer.
+    pub fn with_observer<O2: FrontObserver<T>>(self, observer: O2) -> ParetoFront<T, D, O2>
+    {
+        ParetoFront { front: self.front,
+                      dominance: self.dominance,
+                      observer,
+                      reorder_strategy: self.reorder_strategy,
+                      stats: self.stats }
+    }
+
+    /// Sets the strategy used to reorder the elements of the front when one of them dominates a candidate.
+    ///
+    /// Defaults to `ReorderStrategy::SwapWithPrevious`.
+    pub fn with_reorder_strategy(mut self, reorder_strategy: ReorderStrategy) -> Self
+    {
+        self.front.count_kills(reorder_strategy == ReorderStrategy::CountBased);
+        self.reorder_strategy = reorder_strategy;
+        self
+    }
+
+    /// Returns the strategy used to reorder the elements of the front.
+    pub fn reorder_strategy(&self) -> ReorderStrategy
+    {
+        self.reorder_strategy
+    }
+
+    /// Returns the observer attached to the front.
+    pub fn observer(&self) -> &O
+    {
+        &self.observer
+    }
+
+    /// Returns a mutable reference to the observer attached to the front.
+    pub fn observer_mut(&mut self) -> &mut O
+    {
+        &mut self.observer
+    }
+
+    /// Returns the comparator used as dominance relation by the front.
+    pub fn comparator(&self) -> &D
+    {
+        &self.dominance
+    }
+
+    /// Returns the counters describing the work performed by the front (see `FrontStats`).
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_stats` feature.**
+    #[cfg(feature = "pareto_front_stats")]
+    pub fn stats(&self) -> &FrontStats
+    {
+        &self.stats
+    }
+
+    /// Sets the counters describing the work performed by the front back to zero.
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_stats` feature.**
+    #[cfg(feature = "pareto_front_stats")]
+    pub fn reset_stats(&mut self)
+    {
+        self.stats.reset();
     }
 
-    /// Removes all elements in the front that are dominated by `new_element`,
+    /// Removes all elements in `front` that are dominated by `new_element`,
     /// starting at index `index_start`.
-    fn _remove_dominated_starting_at(&mut self, new_element: &T, index_start: usize)
+    ///
+    /// Kept out of line as it is rarely called: this lets the compiler optimize the scan of `_remove_dominated`.
+    #[inline(never)]
+    fn _remove_dominated_starting_at<Obs: FrontObserver<T>>(front: &mut Members<T>,
+                                                            dominance: &D,
+                                                            observer: &mut Obs,
+                                                            stats: &mut StatsRecorder,
+                                                            new_element: &T,
+                                                            index_start: usize)
     {
         // lists all elements dominated by `new_element`, starting at index `index_start`
         let mut index_dominated_elements = Vec::new();
-        for (index, element) in self.front.iter().enumerate().skip(index_start)
+        for (index, element) in front.as_slice().iter().enumerate().skip(index_start)
         {
-            if new_element.dominate(element)
+            if stats.dominate(dominance, new_element, element)
             {
                 index_dominated_elements.push(index);
             }
@@ -36,11 +189,12 @@ impl<T: Dominate> ParetoFront<T>
         // in reverse order to take into acount that each removed index shift all the following indexes
         for index in index_dominated_elements.into_iter().rev()
         {
-            self.front.swap_remove(index);
+            stats.record_eviction();
+            observer.on_evict(&front.remove(index));
         }
     }
 
-    /// Removes all the elements in the Pareto front that are dominated by `new_element`.
+    /// Removes all the elements in `front` that are dominated by `new_element`.
     /// Returns `true` if `new_element` should be in the Pareto front.
     /// Returns `false` if `new_element` was dominated and, thus, shouldn't be added to the front.
     ///
@@ -48,28 +202,48 @@ impl<T: Dominate> ParetoFront<T>
     /// but is optimized to favour early stopping and cache friendly.
     ///
     /// This operation might *not* preserve the ordering of the elements in the front.
-    fn _remove_dominated(&mut self, new_element: &T) -> bool
+    ///
+    /// Takes the front, comparator, observer and reorder strategy separately (rather than `self`)
+    /// so that it can be used on the content of another front when merging.
+    /// `observer` is notified of the evicted elements.
+    fn _remove_dominated<Obs: FrontObserver<T>>(front: &mut Members<T>,
+                                                dominance: &D,
+                                                observer: &mut Obs,
+                                                reorder_strategy: ReorderStrategy,
+                                                stats: &mut StatsRecorder,
+                                                new_element: &T)
+                                                -> bool
     {
+        // the early stopping below relies on the transitivity of the dominance relation
+        // (an element dominating `new_element` would also dominate any element dominated by `new_element`)
+        if !dominance.is_transitive()
+        {
+            if front.as_slice().iter().any(|element| stats.dominate(dominance, element, new_element))
+            {
+                stats.record_rejection();
+                return false;
+            }
+            Self::_remove_dominated_starting_at(front, dominance, observer, stats, new_element, 0);
+            return true;
+        }
+
         // for all elements of the pareto front, check whether they are dominated or dominate `new_element`
-        for (index, element) in self.front.iter().enumerate()
+        for (index, element) in front.as_slice().iter().enumerate()
         {
-            if element.dominate(new_element)
+            if stats.dominate(dominance, element, new_element)
             {
                 // `new_element` is dominated by `element`, it is thus not part of the Pareto front
-                // swap `element` with the previous element in order to percolate the best elements to the top
+                // moves `element` up in order to percolate the best elements to the top
                 // NOTE: in my benchmarks this brings clear performance benefits by putting "killer" elements first
-                if index > 0
-                {
-                    self.front.swap(index, index - 1);
-                }
+                reorder_strategy.promote(front, index);
+                stats.record_rejection();
                 return false;
             }
-            else if new_element.dominate(element)
+            else if stats.dominate(dominance, new_element, element)
             {
                 // `new_element` dominates `element`, it is thus part of the Pareto front
-                self.front.swap_remove(index);
-                // looks at the rest of the Pareto front to remove any further element that are dominated
-                self._remove_dominated_starting_at(new_element, index);
+                // removes `element` and looks at the rest of the Pareto front to remove any further element that are dominated
+                Self::_remove_dominated_starting_at(front, dominance, observer, stats, new_element, index);
                 return true;
             }
         }
@@ -84,7 +258,7 @@ impl<T: Dominate> ParetoFront<T>
     /// but is optimized to favour early stopping and cache friendly.
     pub fn dominate(&self, new_element: &T) -> bool
     {
-        self.front.iter().any(|element| element.dominate(new_element))
+        self.front.as_slice().iter().any(|element| self.dominance.dominate(element, new_element))
     }
 
     /// Adds `new_element` to the Pareto front.
@@ -128,8 +302,7 @@ impl<T: Dominate> ParetoFront<T>
     /// ```
     pub fn push(&mut self, new_element: T) -> bool
     {
-        // removes dominated elements from the front and checks whether `new_element` should be added
-        let is_pareto_optimal = self._remove_dominated(&new_element);
+        let is_pareto_optimal = self._admit(&new_element);
         // adds `new_element` if needed
         if is_pareto_optimal
         {
@@ -138,27 +311,371 @@ impl<T: Dominate> ParetoFront<T>
         is_pareto_optimal
     }
 
+    /// Removes dominated elements from the front and checks whether `new_element` should be added,
+    /// reporting it to the observer.
+    fn _admit(&mut self, new_element: &T) -> bool
+    {
+        let is_pareto_optimal = Self::_remove_dominated(&mut self.front,
+                                                        &self.dominance,
+                                                        &mut self.observer,
+                                                        self.reorder_strategy,
+                                                        &mut self.stats,
+                                                        new_element);
+        if is_pareto_optimal
+        {
+            self.observer.on_accept(new_element);
+        }
+        else
+        {
+            self.observer.on_reject(new_element);
+        }
+        is_pareto_optimal
+    }
+
+    /// Returns `true` if one of `elites` (elements that are not part of the front) dominates `new_element`,
+    /// in which case `new_element` is reported as rejected to the observer.
+    ///
+    /// The comparisons (and the rejection) are counted in the statistics of the front.
+    #[cfg(feature = "pareto_front_concurrent")]
+    pub(crate) fn reject_if_dominated_by(&mut self, elites: &[T], new_element: &T) -> bool
+    {
+        let is_dominated =
+            elites.iter().any(|elite| self.stats.dominate(&self.dominance, elite, new_element));
+        if is_dominated
+        {
+            self.stats.record_rejection();
+            self.observer.on_reject(new_element);
+        }
+        is_dominated
+    }
+
+    /// Adds `new_element` to the Pareto front.
+    /// Returns the identifier of the element if it is now in the Pareto front.
+    /// Returns `None` if the element was dominated and, thus, not added to the front.
+    ///
+    /// Unlike positions in `as_slice`, the identifier stays valid across mutations of the front
+    /// (as long as the element stays in the front) and can be used with `get`, `contains_id` and `remove`.
+    ///
+    /// This operation has the complexity of `push`.
+    ///
+    /// ```rust
+    /// # use pareto_front::{Dominate, ParetoFront};
+    /// #
+    /// # #[derive(PartialEq)]
+    /// # struct ParetoElement
+    /// # {
+    /// #     cost: usize, // to be minimized
+    /// #     quality: f32, // to be maximized
+    /// # }
+    /// #
+    /// # impl Dominate for ParetoElement
+    /// # {
+    /// #     fn dominate(&self, x: &Self) -> bool
+    /// #     {
+    /// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    /// #     }
+    /// # }
+    /// #
+    /// let mut front = ParetoFront::new();
+    /// let id = front.push_with_id(ParetoElement { cost: 35, quality: 0.5 }).unwrap();
+    /// front.push(ParetoElement { cost: 5, quality: 0.25 });
+    /// assert_eq!(front.get(id).unwrap().cost, 35);
+    ///
+    /// // dominates the first element
+    /// front.push(ParetoElement { cost: 1, quality: 0.75 });
+    /// assert!(!front.contains_id(id));
+    /// ```
+    pub fn push_with_id(&mut self, new_element: T) -> Option<ElementId>
+    {
+        if self._admit(&new_element) { Some(self.front.push_with_id(new_element)) } else { None }
+    }
+
+    /// Returns the element with identifier `id`, or `None` if it is not in the front anymore.
+    ///
+    /// This operation has `O(1)` complexity.
+    pub fn get(&self, id: ElementId) -> Option<&T>
+    {
+        self.front.get(id)
+    }
+
+    /// Returns `true` if the element with identifier `id` is still in the front.
+    ///
+    /// This operation has `O(1)` complexity.
+    pub fn contains_id(&self, id: ElementId) -> bool
+    {
+        self.front.position(id).is_some()
+    }
+
+    /// Removes the element with identifier `id` from the front and returns it,
+    /// or returns `None` if it is not in the front anymore.
+    /// The removed element is reported as evicted to the observer (and counted in the `evictions` statistic).
+    ///
+    /// Note that elements previously rejected because they were dominated by the removed element are *not* recovered.
+    ///
+    /// This operation has `O(1)` complexity and might *not* preserve the ordering of the elements in the front.
+    pub fn remove(&mut self, id: ElementId) -> Option<T>
+    {
+        let position = self.front.position(id)?;
+        let element = self.front.remove(position);
+        self.stats.record_eviction();
+        self.observer.on_evict(&element);
+        Some(element)
+    }
+
+    /// Adds `new_element` to the Pareto front unless one of its objectives is NaN.
+    /// Returns `Ok(true)` if the element is now in the Pareto front.
+    /// Returns `Ok(false)` if the element was dominated and, thus, not added to the front.
+    /// Returns an error, giving the element back, if the element has a NaN objective
+    /// and the `NAN_POLICY` of `T` is not `NanPolicy::Worst`.
+    ///
+    /// Panics in debug mode if the element has a NaN objective and the `NAN_POLICY` of `T` is `NanPolicy::Panic`.
+    ///
+    /// This operation has the complexity of `push`.
+    pub fn try_push(&mut self, new_element: T) -> Result<bool, NanError<T>>
+        where T: Objectives
+    {
+        if let Some(objective) = find_nan(&new_element)
+        {
+            match T::NAN_POLICY
+            {
+                NanPolicy::Worst => (),
+                NanPolicy::Reject => return Err(NanError { element: new_element, objective }),
+                NanPolicy::Panic =>
+                {
+                    debug_assert!(false, "objective number {} is NaN", objective);
+                    return Err(NanError { element: new_element, objective });
+                }
+            }
+        }
+        Ok(self.push(new_element))
+    }
+
     /// Adds the content of `pareto_front` to the Pareto front.
+    /// The comparator and observer of `self` are used, the observer of `pareto_front` is dropped.
     ///
     /// This operation has `O(n*m)` complexity
     /// where `n` is the number of elements in `self`
     /// and `m` is the number of elements in `pareto_front`
     /// but is optimized to favour early stopping.
-    pub fn merge(&mut self, pareto_front: ParetoFront<T>)
+    pub fn merge(&mut self, pareto_front: ParetoFront<T, D, O>)
     {
-        // set the largest front aside
-        let mut largest_front = pareto_front.front;
-        if largest_front.len() < self.front.len()
+        self.stats.record_merge();
+        self.merge_members(pareto_front.front, false);
+    }
+
+    /// Adds the content of `pareto_front`, whose elements have already been reported to the observer of `self`
+    /// (typically through a clone of the observer), to the Pareto front.
+    /// Its elements that do not make it into the front are thus reported as evicted rather than rejected.
+    #[cfg_attr(not(feature = "pareto_front_concurrent"), allow(dead_code))]
+    pub(crate) fn merge_accepted(&mut self, pareto_front: ParetoFront<T, D, O>)
+    {
+        // the work done by the other front was done on behalf of this front
+        #[cfg(feature = "pareto_front_stats")]
         {
-            std::mem::swap(&mut self.front, &mut largest_front);
+            self.stats += pareto_front.stats;
         }
-        // for all the elements in the largest front, remove dominated elements from the smallest front
-        // the largest front keeps only the elements that should be in the final Pareto front
-        largest_front.retain(|x| self._remove_dominated(x));
-        // extends the largest front with the content of the smallest front
-        // and make it our front
-        std::mem::swap(&mut self.front, &mut largest_front);
-        self.front.extend(largest_front);
+        self.stats.record_merge();
+        self.merge_members(pareto_front.front, true);
+    }
+
+    /// Merges all `fronts` into a single Pareto front.
+    /// Returns `None` if there is no front to merge.
+    /// The comparator and observer of the first front are used, the observers of the other fronts are dropped.
+    ///
+    /// Fronts are merged pairwise, as a binary tree, rather than one after the other into a single accumulator.
+    /// Sequentially, this costs about as much as merging them one after the other (see the `merge_64_fronts` group of `benches/merge.rs`)
+    /// but the merges of a given level of the tree are independent:
+    /// `par_merge_all` does them in parallel, reducing the depth of the computation from `t` to `log(t)` merges
+    /// where `t` is the number of fronts.
+    ///
+    /// ```rust
+    /// # use pareto_front::{Dominate, ParetoFront};
+    /// #
+    /// # #[derive(PartialEq)]
+    /// # struct ParetoElement
+    /// # {
+    /// #     cost: usize, // to be minimized
+    /// #     quality: f32, // to be maximized
+    /// # }
+    /// #
+    /// # impl Dominate for ParetoElement
+    /// # {
+    /// #     fn dominate(&self, x: &Self) -> bool
+    /// #     {
+    /// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    /// #     }
+    /// # }
+    /// #
+    /// let fronts = (0..4).map(|i| {
+    ///                        let mut front = ParetoFront::new();
+    ///                        front.push(ParetoElement { cost: 10 * i, quality: 0.1 * (i as f32) });
+    ///                        front
+    ///                    });
+    ///
+    /// let front = ParetoFront::merge_all(fronts).unwrap();
+    /// assert_eq!(front.len(), 4);
+    /// ```
+    pub fn merge_all<I: IntoIterator<Item = ParetoFront<T, D, O>>>(fronts: I) -> Option<Self>
+    {
+        Self::merge_tree(fronts.into_iter().collect(), Self::merge)
+    }
+
+    /// Merges all `fronts` pairwise, as a binary tree, using `merge` to merge two fronts.
+    pub(crate) fn merge_tree(mut fronts: Vec<Self>, merge: fn(&mut Self, Self)) -> Option<Self>
+    {
+        while fronts.len() > 1
+        {
+            // merges consecutive fronts, halving the number of fronts at each round
+            let mut merged_fronts = Vec::with_capacity(fronts.len().div_ceil(2));
+            let mut fronts_iter = fronts.into_iter();
+            while let Some(mut front) = fronts_iter.next()
+            {
+                if let Some(other_front) = fronts_iter.next()
+                {
+                    merge(&mut front, other_front);
+                }
+                merged_fronts.push(front);
+            }
+            fronts = merged_fronts;
+        }
+        fronts.pop()
+    }
+
+    /// Adds the elements of `other_front` to the Pareto front.
+    ///
+    /// If `already_accepted` is `true`, the elements of `other_front` are considered to have already been reported
+    /// to the observer: the ones that do not make it into the front are reported as evicted.
+    fn merge_members(&mut self, mut other_front: Members<T>, already_accepted: bool)
+    {
+        let mut other_side = MergedSide { observer: &mut self.observer, already_accepted };
+        // NOTE: we iterate on the largest front and remove dominated elements from the smallest front
+        //       as scanning the smallest front is cheaper and more likely to stop early
+        if other_front.as_slice().len() >= self.front.as_slice().len()
+        {
+            // the other front keeps only the elements that are not dominated by our elements
+            let mut other_front = other_front.into_vec();
+            other_front.retain(|x| {
+                           let is_pareto_optimal = Self::_remove_dominated(&mut self.front,
+                                                                           &self.dominance,
+                                                                           other_side.observer,
+                                                                           self.reorder_strategy,
+                                                                           &mut self.stats,
+                                                                           x);
+                           if !is_pareto_optimal
+                           {
+                               other_side.on_evict(x);
+                           }
+                           is_pareto_optimal
+                       });
+            other_front.into_iter().for_each(|x| {
+                                       other_side.on_accept(&x);
+                                       self.front.push(x);
+                                   });
+        }
+        else
+        {
+            // we keep only the elements that are not dominated by the elements of the other front
+            self.front.retain(|x| {
+                          let is_pareto_optimal = Self::_remove_dominated(&mut other_front,
+                                                                          &self.dominance,
+                                                                          &mut other_side,
+                                                                          self.reorder_strategy,
+                                                                          &mut self.stats,
+                                                                          x);
+                          if !is_pareto_optimal
+                          {
+                              other_side.observer.on_evict(x);
+                          }
+                          is_pareto_optimal
+                      });
+            other_front.into_vec().into_iter().for_each(|x| {
+                                                  other_side.on_accept(&x);
+                                                  self.front.push(x);
+                                              });
+        }
+    }
+
+    /// Keeps only the elements of the front for which `predicate` returns `true`.
+    ///
+    /// Removing elements cannot break the front as the remaining elements are still non-dominated.
+    /// Removed elements are reported as evicted to the observer.
+    pub(crate) fn retain<F: FnMut(&T) -> bool>(&mut self, mut predicate: F)
+    {
+        let observer = &mut self.observer;
+        self.front.retain(|x| {
+                      let is_kept = predicate(x);
+                      if !is_kept
+                      {
+                          observer.on_evict(x);
+                      }
+                      is_kept
+                  });
+    }
+
+    /// Returns a checkpoint that can later be given to `changes_since`
+    /// to know which elements entered and left the front in the meantime.
+    ///
+    /// The front starts keeping track of its changes when the first checkpoint is taken,
+    /// this uses memory proportional to the number of changes (see `forget_changes_before`).
+    ///
+    /// This operation has `O(1)` complexity.
+    pub fn checkpoint(&mut self) -> Checkpoint
+    {
+        self.front.checkpoint()
+    }
+
+    /// Returns the elements that entered the front since `checkpoint` and are still in it
+    /// as well as the identifiers of the elements that were in the front at the time of `checkpoint` but left it since.
+    ///
+    /// This operation has `O(n+c)` complexity
+    /// where `n` is the number of elements currently in the front
+    /// and `c` the number of changes since `checkpoint`.
+    ///
+    /// Panics if `checkpoint` does not come from this front (checkpoints taken on a clone are refused)
+    /// or is older than the checkpoint given to `forget_changes_before`.
+    ///
+    /// ```rust
+    /// # use pareto_front::{Dominate, ParetoFront};
+    /// #
+    /// # #[derive(PartialEq)]
+    /// # struct ParetoElement
+    /// # {
+    /// #     cost: usize, // to be minimized
+    /// #     quality: f32, // to be maximized
+    /// # }
+    /// #
+    /// # impl Dominate for ParetoElement
+    /// # {
+    /// #     fn dominate(&self, x: &Self) -> bool
+    /// #     {
+    /// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    /// #     }
+    /// # }
+    /// #
+    /// let mut front = ParetoFront::new();
+    /// front.push(ParetoElement { cost: 35, quality: 0.25 });
+    /// let checkpoint = front.checkpoint();
+    ///
+    /// // dominates the previous element
+    /// front.push(ParetoElement { cost: 5, quality: 0.5 });
+    ///
+    /// let diff = front.changes_since(checkpoint);
+    /// assert_eq!(diff.added.len(), 1);
+    /// assert_eq!(diff.removed.len(), 1);
+    /// ```
+    pub fn changes_since(&self, checkpoint: Checkpoint) -> FrontDiff<'_, T>
+    {
+        self.front.changes_since(checkpoint)
+    }
+
+    /// Forgets the changes that happened before `checkpoint`, freeing the associated memory.
+    ///
+    /// Older checkpoints cannot be given to `changes_since` anymore.
+    ///
+    /// Panics if `checkpoint` does not come from this front.
+    pub fn forget_changes_before(&mut self, checkpoint: Checkpoint)
+    {
+        self.front.forget_changes_before(checkpoint)
     }
 
     /// Extracts a slice containing the entire Pareto front.
@@ -170,45 +687,378 @@ impl<T: Dominate> ParetoFront<T>
     /// Returns the number of elements currently in the Pareto front.
     pub fn len(&self) -> usize
     {
-        self.front.len()
+        self.front.as_slice().len()
     }
 
     /// Returns `true` if the Pareto front contains no elements.
     pub fn is_empty(&self) -> bool
     {
-        self.front.is_empty()
+        self.front.as_slice().is_empty()
     }
 
     /// Returns an iterator over the Pareto front.
     pub fn iter(&self) -> Iter<'_, T>
     {
-        self.front.iter()
+        self.front.as_slice().iter()
+    }
+
+    /// Returns an iterator over the elements of the Pareto front and their identifiers.
+    ///
+    /// An identifier stays associated with the same element as long as it is in the front.
+    /// Identifiers are only kept once they have been requested (by this method, `push_with_id` or `checkpoint`),
+    /// the first call gives new identifiers to the elements already in the front.
+    pub fn iter_with_ids(&mut self) -> impl Iterator<Item = (ElementId, &T)>
+    {
+        self.front.iter_with_ids()
     }
 
     // no `iter_mut` as the mutation could invalidate the front
+
+    /// Returns `true` if all the elements of the Pareto front are also elements of `other`.
+    ///
+    /// This operation has `O(n*m)` complexity
+    /// where `n` is the number of elements in `self`
+    /// and `m` is the number of elements in `other`.
+    pub fn is_subset<O2>(&self, other: &ParetoFront<T, D, O2>) -> bool
+        where T: PartialEq
+    {
+        self.iter().all(|x| other.front.as_slice().contains(x))
+    }
+
+    /// Returns the Pareto front of the union of the elements of `self` and `other`.
+    ///
+    /// This is a non-consuming equivalent of `merge` and has the same complexity.
+    /// The result does not have an observer attached.
+    pub fn union<O2>(&self, other: &ParetoFront<T, D, O2>) -> ParetoFront<T, D>
+        where T: Clone,
+              D: Clone
+    {
+        let mut result = ParetoFront { front: self.front.clone(),
+                                       dominance: self.dominance.clone(),
+                                       observer: NoObserver,
+                                       reorder_strategy: self.reorder_strategy,
+                                       stats: StatsRecorder::new() };
+        result.merge_members(other.front.clone(), false);
+        result
+    }
+}
+
+#[cfg(feature = "pareto_front_rayon")]
+impl<T: Send, D: Dominance<T> + Send, O: FrontObserver<T> + Send> ParetoFront<T, D, O>
+{
+    /// Merges all `fronts` into a single Pareto front, in parallel.
+    /// Returns `None` if there is no front to merge.
+    ///
+    /// Like `merge_all`, fronts are merged pairwise as a binary tree, but independent merges are done in parallel.
+    /// Note that the front used as left operand of each merge (and thus its comparator and observer)
+    /// is decided by the parallel reduction.
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_rayon` feature.**
+    pub fn par_merge_all<I: IntoParallelIterator<Item = ParetoFront<T, D, O>>>(fronts: I) -> Option<Self>
+    {
+        Self::par_merge_tree(fronts, Self::merge)
+    }
+
+    /// Merges all `fronts` pairwise, as a binary tree, in parallel, using `merge` to merge two fronts.
+    pub(crate) fn par_merge_tree<I: IntoParallelIterator<Item = ParetoFront<T, D, O>>>(fronts: I,
+                                                                                       merge: fn(&mut Self,
+                                                                                          Self))
+                                                                                       -> Option<Self>
+    {
+        // rayon reduces recursively, halving the sequence at each step, which merges the fronts as a binary tree
+        fronts.into_par_iter().reduce_with(|mut front, other_front| {
+                                  merge(&mut front, other_front);
+                                  front
+                              })
+    }
+
+    /// Returns a parallel iterator over the Pareto front.
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_rayon` feature.**
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T>
+        where T: Sync
+    {
+        self.front.as_slice().par_iter()
+    }
+}
+
+/// Size of the front under which `par_push` falls back to `push`,
+/// the overhead of splitting the scan between threads is not amortized on smaller fronts.
+#[cfg(feature = "pareto_front_rayon")]
+const PAR_PUSH_THRESHOLD: usize = 16384;
+
+/// Result of the parallel scan of a front by `par_push`.
+#[cfg(feature = "pareto_front_rayon")]
+enum ScanResult
+{
+    /// the element at the given index dominates the candidate
+    Dominated(usize),
+    /// the candidate dominates an element
+    Dominates
 }
 
-impl<T: Dominate> Default for ParetoFront<T>
+#[cfg(feature = "pareto_front_rayon")]
+impl<T: Send + Sync, D: Dominance<T> + Sync, O: FrontObserver<T>> ParetoFront<T, D, O>
+{
+    /// Removes all elements in `front` that are dominated by `new_element`,
+    /// starting at index `index_start`, splitting the scan between threads.
+    fn _par_remove_dominated_starting_at<Obs: FrontObserver<T>>(front: &mut Members<T>,
+                                                                dominance: &D,
+                                                                observer: &mut Obs,
+                                                                stats: &mut StatsRecorder,
+                                                                new_element: &T,
+                                                                index_start: usize)
+    {
+        // lists all elements dominated by `new_element`, starting at index `index_start`
+        let index_dominated_elements: Vec<usize> =
+            front.as_slice()[index_start..].par_iter()
+                                           .enumerate()
+                                           .filter(|(_, element)| dominance.dominate(new_element, element))
+                                           .map(|(index, _)| index_start + index)
+                                           .collect();
+
+        // removes the elements at the listed indexes
+        // in reverse order to take into acount that each removed index shift all the following indexes
+        for index in index_dominated_elements.into_iter().rev()
+        {
+            stats.record_eviction();
+            observer.on_evict(&front.remove(index));
+        }
+    }
+
+    /// Adds `new_element` to the Pareto front, splitting the scan of the front between threads.
+    /// Returns `true` if the element is now in the Pareto front.
+    /// Returns `false` if the element was dominated and, thus, not added to the front.
+    ///
+    /// The scan stops, on all threads, as soon as an element dominating `new_element` is found.
+    /// This is only worth it for very large fronts (millions of elements, typical of high dimensional data),
+    /// below 16384 elements this operation falls back to `push`.
+    ///
+    /// The comparisons done by the parallel scan are not counted in the statistics of the front.
+    ///
+    /// **NOTE: This method is only available when enabling the `pareto_front_rayon` feature.**
+    pub fn par_push(&mut self, new_element: T) -> bool
+    {
+        if self.front.as_slice().len() < PAR_PUSH_THRESHOLD
+        {
+            return self.push(new_element);
+        }
+
+        let is_pareto_optimal = if self.dominance.is_transitive()
+        {
+            // as the front does not contain elements dominating each other, transitivity ensures that
+            // we cannot find both an element dominating `new_element` and an element dominated by `new_element`
+            // we can thus stop at the first element found by any thread
+            let dominance = &self.dominance;
+            let classify = |(index, element): (usize, &T)| {
+                if dominance.dominate(element, &new_element)
+                {
+                    Some(ScanResult::Dominated(index))
+                }
+                else if dominance.dominate(&new_element, element)
+                {
+                    Some(ScanResult::Dominates)
+                }
+                else
+                {
+                    None
+                }
+            };
+            let scan_result = self.front.as_slice().par_iter().enumerate().find_map_any(classify);
+            match scan_result
+            {
+                Some(ScanResult::Dominated(index)) =>
+                {
+                    self.reorder_strategy.promote(&mut self.front, index);
+                    false
+                }
+                Some(ScanResult::Dominates) =>
+                {
+                    // the element found is not necessarily the first dominated element, we scan the whole front
+                    Self::_par_remove_dominated_starting_at(&mut self.front,
+                                                            &self.dominance,
+                                                            &mut self.observer,
+                                                            &mut self.stats,
+                                                            &new_element,
+                                                            0);
+                    true
+                }
+                None => true
+            }
+        }
+        else
+        {
+            let dominance = &self.dominance;
+            let is_dominated =
+                self.front.as_slice().par_iter().any(|element| dominance.dominate(element, &new_element));
+            if !is_dominated
+            {
+                Self::_par_remove_dominated_starting_at(&mut self.front,
+                                                        &self.dominance,
+                                                        &mut self.observer,
+                                                        &mut self.stats,
+                                                        &new_element,
+                                                        0);
+            }
+            !is_dominated
+        };
+
+        if is_pareto_optimal
+        {
+            self.observer.on_accept(&new_element);
+            self.front.push(new_element);
+        }
+        else
+        {
+            self.stats.record_rejection();
+            self.observer.on_reject(&new_element);
+        }
+        is_pareto_optimal
+    }
+}
+
+impl<T: PartialEq, D, O, O2> PartialEq<ParetoFront<T, D, O2>> for ParetoFront<T, D, O>
+{
+    /// Two Pareto fronts are equal if they contain the same elements, independently of their order.
+    ///
+    /// As equal elements do not dominate each other, a front can contain duplicates:
+    /// fronts are compared as multisets, each element being present the same number of times in both fronts.
+    ///
+    /// This operation has `O(n²)` complexity where `n` is the number of elements in the fronts.
+    fn eq(&self, other: &ParetoFront<T, D, O2>) -> bool
+    {
+        let (front, other_front) = (self.front.as_slice(), other.front.as_slice());
+        if front.len() != other_front.len()
+        {
+            return false;
+        }
+        // matches each element with a distinct, equal, element of the other front
+        let mut is_matched = vec![false; other_front.len()];
+        front.iter().all(|x| {
+                        let unmatched_equal = other_front.iter()
+                                                         .zip(is_matched.iter_mut())
+                                                         .find(|(y, is_matched)| !**is_matched && (x == *y));
+                        match unmatched_equal
+                        {
+                            Some((_, is_matched)) =>
+                            {
+                                *is_matched = true;
+                                true
+                            }
+                            None => false
+                        }
+                    })
+    }
+}
+
+impl<T: Eq, D, O> Eq for ParetoFront<T, D, O> {}
+
+/// Serialized form of a `ParetoFront`, deserialized before validating its elements.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "ParetoFront")]
+struct SerializedParetoFront<T>
+{
+    front: Vec<T>
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, D: Dominance<T> + Default, O: FrontObserver<T> + Default>
+    ParetoFront<T, D, O>
+{
+    /// Deserializes a front, failing if one of its elements is dominated by another one.
+    /// The error lists the indices of the dominated elements.
+    ///
+    /// This is what the `Deserialize` implementation of `ParetoFront` does,
+    /// it can also be used with `#[serde(deserialize_with = "ParetoFront::deserialize_strict")]`.
+    ///
+    /// This operation has `O(n²)` complexity where `n` is the number of elements in the front.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_serde` feature.**
+    pub fn deserialize_strict<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error>
+    {
+        let elements = <SerializedParetoFront<T> as serde::Deserialize>::deserialize(deserializer)?.front;
+        Self::from_vec_strict(elements).map_err(|dominated_indices| {
+            let message = format!("elements at indices {dominated_indices:?} are dominated by other elements of the front");
+            serde::de::Error::custom(message)
+        })
+    }
+
+    /// Deserializes a front, pushing all of its elements into an empty front
+    /// so that elements dominated by other elements are dropped.
+    ///
+    /// Meant to be used with `#[serde(deserialize_with = "ParetoFront::deserialize_repair")]`
+    /// to load fronts that might have been corrupted or edited by hand.
+    ///
+    /// This operation has `O(n²)` complexity where `n` is the number of elements in the front.
+    ///
+    /// **NOTE: This function is only available when enabling the `pareto_front_serde` feature.**
+    pub fn deserialize_repair<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error>
+    {
+        let elements = <SerializedParetoFront<T> as serde::Deserialize>::deserialize(deserializer)?.front;
+        Ok(elements.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>, D: Dominance<T> + Default, O: FrontObserver<T> + Default>
+    serde::Deserialize<'de> for ParetoFront<T, D, O>
+{
+    /// Deserializes a front, failing if one of its elements is dominated by another one (see `deserialize_strict`).
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error>
+    {
+        Self::deserialize_strict(deserializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T, D: Dominance<T> + Default, O: FrontObserver<T> + Default> ParetoFront<T, D, O>
+{
+    /// Builds a front out of elements that are expected not to dominate each other,
+    /// failing with the indices of the elements dominated by other elements otherwise.
+    ///
+    /// This is used to load saved fronts, elements dominated by other elements being a sign of corruption.
+    /// This operation has `O(n²)` complexity where `n` is the number of elements.
+    pub(crate) fn from_vec_strict(elements: Vec<T>) -> Result<Self, Vec<usize>>
+    {
+        let dominance = D::default();
+        let is_dominated = |x: &T| elements.iter().any(|element| dominance.dominate(element, x));
+        let dominated_indices: Vec<usize> =
+            (0..elements.len()).filter(|&i| is_dominated(&elements[i])).collect();
+        if !dominated_indices.is_empty()
+        {
+            return Err(dominated_indices);
+        }
+        // elements are given new identifiers when first requested
+        Ok(ParetoFront { front: Members::from_vec(elements), dominance, ..Self::default() })
+    }
+}
+
+impl<T, D: Dominance<T> + Default, O: FrontObserver<T> + Default> Default for ParetoFront<T, D, O>
 {
     /// Default value.
     fn default() -> Self
     {
         // Manually implemented so as to not require `T` to implement `Default`.
-        Self::new()
+        ParetoFront { front: Members::new(),
+                      dominance: D::default(),
+                      observer: O::default(),
+                      reorder_strategy: ReorderStrategy::default(),
+                      stats: StatsRecorder::new() }
     }
 }
 
-impl<T: Dominate> From<ParetoFront<T>> for Vec<T>
+impl<T, D, O> From<ParetoFront<T, D, O>> for Vec<T>
 {
     /// Converts the Pareto front into a vector.
     /// This operation is free as the underlying datastructure is a vector.
-    fn from(front: ParetoFront<T>) -> Vec<T>
+    fn from(front: ParetoFront<T, D, O>) -> Vec<T>
     {
-        front.front
+        front.front.into_vec()
     }
 }
 
-impl<T: Dominate> IntoIterator for ParetoFront<T>
+impl<T, D, O> IntoIterator for ParetoFront<T, D, O>
 {
     type Item = T;
     type IntoIter = std::vec::IntoIter<T>;
@@ -216,11 +1066,11 @@ impl<T: Dominate> IntoIterator for ParetoFront<T>
     /// Creates an iterator from a `ParetoFront`.
     fn into_iter(self) -> Self::IntoIter
     {
-        self.front.into_iter()
+        self.front.into_vec().into_iter()
     }
 }
 
-impl<T: Dominate> FromIterator<T> for ParetoFront<T>
+impl<T, D: Dominance<T> + Default, O: FrontObserver<T> + Default> FromIterator<T> for ParetoFront<T, D, O>
 {
     /// Implements the `FromIterator` trait to enable the collection of an iterator into a `ParetoFront`.
     ///
@@ -255,7 +1105,7 @@ impl<T: Dominate> FromIterator<T> for ParetoFront<T>
     /// ```
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
     {
-        let mut front = ParetoFront::new();
+        let mut front = ParetoFront::default();
 
         for x in iter
         {
@@ -266,7 +1116,7 @@ impl<T: Dominate> FromIterator<T> for ParetoFront<T>
     }
 }
 
-impl<T: Dominate> Extend<T> for ParetoFront<T>
+impl<T, D: Dominance<T>, O: FrontObserver<T>> Extend<T> for ParetoFront<T, D, O>
 {
     /// Implements the `Extend` trait to extend a `ParetoFront` with the content of an iterator.
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
@@ -280,3 +1130,42 @@ impl<T: Dominate> Extend<T> for ParetoFront<T>
         }
     }
 }
+
+#[cfg(feature = "pareto_front_rayon")]
+impl<T: Send, D: Dominance<T> + Clone + Send + Sync + Default> FromParallelIterator<T> for ParetoFront<T, D>
+{
+    /// Implements the `FromParallelIterator` trait to enable the collection of a parallel iterator into a `ParetoFront`.
+    ///
+    /// **NOTE: This trait is only implemented when enabling the `pareto_front_rayon` feature.**
+    fn from_par_iter<I: IntoParallelIterator<Item = T>>(par_iter: I) -> Self
+    {
+        let mut front = ParetoFront::default();
+        front.par_extend(par_iter);
+        front
+    }
+}
+
+#[cfg(feature = "pareto_front_rayon")]
+impl<T: Send, D: Dominance<T> + Clone + Send + Sync> ParallelExtend<T> for ParetoFront<T, D>
+{
+    /// Implements the `ParallelExtend` trait to extend a `ParetoFront` with the content of a parallel iterator.
+    ///
+    /// Each rayon task pushes its elements into its own front (using a clone of the comparator),
+    /// the fronts are then merged as a binary tree and, finally, into `self`.
+    ///
+    /// **NOTE: This trait is only implemented when enabling the `pareto_front_rayon` feature.**
+    fn par_extend<I: IntoParallelIterator<Item = T>>(&mut self, par_iter: I)
+    {
+        let (dominance, reorder_strategy) = (&self.dominance, self.reorder_strategy);
+        let fronts = par_iter.into_par_iter().fold(|| ParetoFront::with_comparator(dominance.clone()).with_reorder_strategy(reorder_strategy),
+                                                   |mut front, x| {
+                                                       front.push(x);
+                                                       front
+                                                   });
+        // `merge_accepted` keeps the statistics of the task fronts, there is no observer to report to
+        if let Some(front) = ParetoFront::par_merge_tree(fronts, ParetoFront::merge_accepted)
+        {
+            self.merge_accepted(front);
+        }
+    }
+}
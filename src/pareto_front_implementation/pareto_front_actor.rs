@@ -0,0 +1,331 @@
+use crate::{Dominance, Dominate, ParetoFront};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, SendError, SyncSender, TryRecvError, TrySendError};
+use std::thread::{self, JoinHandle};
+
+/// Number of messages that can be waiting for the actor before `push` blocks, used by `ParetoFrontActor::new`.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// Bit of the shared state set once the actor is shutting down, the other bits count the sends in progress.
+const CLOSED: usize = 1 << (usize::BITS - 1);
+
+/// Function run by the actor on its front.
+type Query<T, D> = Box<dyn FnOnce(&ParetoFront<T, D>) + Send>;
+
+/// Request sent to the thread owning the front.
+enum Message<T, D>
+{
+    /// adds an element to the front
+    Push(T),
+    /// adds several elements to the front
+    PushBatch(Vec<T>),
+    /// runs a function on the front (used to answer queries such as snapshots)
+    Query(Query<T, D>),
+    /// stops the actor, returning the front
+    Shutdown
+}
+
+/// Represents a Pareto front owned by a dedicated thread and fed through a channel.
+///
+/// Unlike `ConcurrentParetoFront`, which keeps one front per OS thread,
+/// this front does not care about the thread (or task) pushing into it:
+/// any number of `ParetoFrontHandle` can send elements to the actor, which pushes them into its front in order.
+/// The channel is bounded, `push` blocks when the actor is lagging behind (`try_push` does not block).
+/// Sending elements in batches (using `push_batch`) reduces the cost of going through the channel.
+///
+/// As elements are pushed asynchronously, `push` does not tell whether the element made it into the front.
+///
+/// The actor stops when `shutdown` is called on it or on one of its handles (or once all handles are dropped).
+/// Every message sent successfully, from any handle, is processed before the actor stops:
+/// sends started after a shutdown fail.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_concurrent` feature.**
+///
+/// ```rust
+/// # use pareto_front::{Dominate, ParetoFrontActor};
+/// #
+/// # #[derive(PartialEq, Clone)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// let actor = ParetoFrontActor::new();
+///
+/// // handles can be sent to other threads or tasks
+/// let handle = actor.handle();
+/// let producer = std::thread::spawn(move || {
+///     handle.push(ParetoElement { cost: 35, quality: 0.5 }).unwrap();
+///     let batch = vec![ParetoElement { cost: 5, quality: 0.25 }, ParetoElement { cost: 50, quality: 0.25 }];
+///     handle.push_batch(batch).unwrap();
+/// });
+/// producer.join().unwrap();
+///
+/// // all elements sent before the snapshot are taken into account
+/// assert_eq!(actor.snapshot().unwrap().len(), 2);
+///
+/// let front = actor.shutdown();
+/// assert_eq!(front.len(), 2);
+/// ```
+pub struct ParetoFrontActor<T, D = crate::NaturalDominance>
+{
+    handle: ParetoFrontHandle<T, D>,
+    thread: JoinHandle<ParetoFront<T, D>>
+}
+
+/// Cloneable handle used to send elements to a `ParetoFrontActor`.
+///
+/// Operations on a handle fail once the actor has been shut down.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_concurrent` feature.**
+pub struct ParetoFrontHandle<T, D = crate::NaturalDominance>
+{
+    sender: SyncSender<Message<T, D>>,
+    /// shared by all handles and the actor, see `CLOSED`
+    state: Arc<AtomicUsize>
+}
+
+/// Counts a send in progress, for as long as it lives.
+struct SendGuard<'a>(&'a AtomicUsize);
+
+impl Drop for SendGuard<'_>
+{
+    fn drop(&mut self)
+    {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl<T: Dominate + Send + 'static> ParetoFrontActor<T>
+{
+    /// Spawns a new actor owning an empty Pareto front.
+    pub fn new() -> Self
+    {
+        Self::with_front(ParetoFront::new(), DEFAULT_CAPACITY)
+    }
+}
+
+impl<T: Send + 'static, D: Dominance<T> + Send + 'static> ParetoFrontActor<T, D>
+{
+    /// Spawns a new actor owning `front`.
+    /// Up to `capacity` messages can be waiting for the actor before `push` blocks.
+    pub fn with_front(front: ParetoFront<T, D>, capacity: usize) -> Self
+    {
+        let (sender, receiver) = mpsc::sync_channel(capacity);
+        let state = Arc::new(AtomicUsize::new(0));
+        let actor_state = state.clone();
+        let thread = thread::spawn(move || Self::run(front, receiver, &actor_state));
+        ParetoFrontActor { handle: ParetoFrontHandle { sender, state }, thread }
+    }
+
+    /// Processes messages until the actor is shut down or all handles are dropped.
+    fn run(mut front: ParetoFront<T, D>,
+           receiver: Receiver<Message<T, D>>,
+           state: &AtomicUsize)
+           -> ParetoFront<T, D>
+    {
+        for message in &receiver
+        {
+            if !Self::process(&mut front, message)
+            {
+                break;
+            }
+        }
+
+        // refuses new sends then processes the messages of the sends that succeeded
+        state.fetch_or(CLOSED, Ordering::SeqCst);
+        loop
+        {
+            // once no send is in progress, all messages sent are in the channel
+            let nb_sends_in_progress = state.load(Ordering::SeqCst) & !CLOSED;
+            match receiver.try_recv()
+            {
+                Ok(message) =>
+                {
+                    Self::process(&mut front, message);
+                }
+                // a sender might be blocked on a full channel, which we are emptying
+                Err(TryRecvError::Empty) if nb_sends_in_progress != 0 => thread::yield_now(),
+                Err(_) => break
+            }
+        }
+        front
+    }
+
+    /// Processes a single message, returns `false` if it asks the actor to stop.
+    fn process(front: &mut ParetoFront<T, D>, message: Message<T, D>) -> bool
+    {
+        match message
+        {
+            Message::Push(new_element) =>
+            {
+                front.push(new_element);
+            }
+            Message::PushBatch(new_elements) => front.extend(new_elements),
+            Message::Query(query) => query(front),
+            Message::Shutdown => return false
+        }
+        true
+    }
+
+    /// Returns a new handle to the actor.
+    pub fn handle(&self) -> ParetoFrontHandle<T, D>
+    {
+        self.handle.clone()
+    }
+
+    /// Sends `new_element` to the actor, see `ParetoFrontHandle::push`.
+    pub fn push(&self, new_element: T) -> Result<(), SendError<T>>
+    {
+        self.handle.push(new_element)
+    }
+
+    /// Sends `new_elements` to the actor as a single message, see `ParetoFrontHandle::push_batch`.
+    pub fn push_batch(&self, new_elements: Vec<T>) -> Result<(), SendError<Vec<T>>>
+    {
+        self.handle.push_batch(new_elements)
+    }
+
+    /// Returns a copy of the front once all previously sent elements have been pushed, see `ParetoFrontHandle::snapshot`.
+    pub fn snapshot(&self) -> Option<ParetoFront<T, D>>
+        where T: Clone,
+              D: Clone
+    {
+        self.handle.snapshot()
+    }
+
+    /// Stops the actor, once all previously sent elements have been pushed, and returns its front.
+    ///
+    /// Later operations on the handles of the actor fail.
+    /// If the actor panicked (in the comparator for example), the panic is propagated.
+    pub fn shutdown(self) -> ParetoFront<T, D>
+    {
+        // the send can only fail if the actor is already stopping (or panicked, in which case joining reports why)
+        let _ = self.handle.shutdown();
+        self.thread.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
+
+impl<T: Dominate + Send + 'static> Default for ParetoFrontActor<T>
+{
+    /// Default value.
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T, D> Clone for ParetoFrontHandle<T, D>
+{
+    /// Returns a new handle to the same actor.
+    fn clone(&self) -> Self
+    {
+        // Manually implemented so as to not require `T` and `D` to implement `Clone`.
+        ParetoFrontHandle { sender: self.sender.clone(), state: self.state.clone() }
+    }
+}
+
+impl<T: Send + 'static, D: Send + 'static> ParetoFrontHandle<T, D>
+{
+    /// Registers a send, returns `None` if the actor is shutting down.
+    fn start_send(&self) -> Option<SendGuard<'_>>
+    {
+        let state = self.state.fetch_add(1, Ordering::SeqCst);
+        let guard = SendGuard(&self.state);
+        (state & CLOSED == 0).then_some(guard)
+    }
+
+    /// Sends `new_element` to the actor, blocking if the channel is full.
+    /// Returns the element if the actor has been shut down.
+    pub fn push(&self, new_element: T) -> Result<(), SendError<T>>
+    {
+        let Some(_guard) = self.start_send()
+        else
+        {
+            return Err(SendError(new_element));
+        };
+        // on failure, the message (and thus the element) is given back
+        match self.sender.send(Message::Push(new_element))
+        {
+            Err(SendError(Message::Push(new_element))) => Err(SendError(new_element)),
+            _ => Ok(())
+        }
+    }
+
+    /// Sends `new_element` to the actor, failing instead of blocking if the channel is full
+    /// (which makes it usable from an async task).
+    pub fn try_push(&self, new_element: T) -> Result<(), TrySendError<T>>
+    {
+        let Some(_guard) = self.start_send()
+        else
+        {
+            return Err(TrySendError::Disconnected(new_element));
+        };
+        match self.sender.try_send(Message::Push(new_element))
+        {
+            Err(TrySendError::Full(Message::Push(new_element))) => Err(TrySendError::Full(new_element)),
+            Err(TrySendError::Disconnected(Message::Push(new_element))) =>
+            {
+                Err(TrySendError::Disconnected(new_element))
+            }
+            _ => Ok(())
+        }
+    }
+
+    /// Sends `new_elements` to the actor as a single message, blocking if the channel is full.
+    /// Returns the elements if the actor has been shut down.
+    pub fn push_batch(&self, new_elements: Vec<T>) -> Result<(), SendError<Vec<T>>>
+    {
+        let Some(_guard) = self.start_send()
+        else
+        {
+            return Err(SendError(new_elements));
+        };
+        match self.sender.send(Message::PushBatch(new_elements))
+        {
+            Err(SendError(Message::PushBatch(new_elements))) => Err(SendError(new_elements)),
+            _ => Ok(())
+        }
+    }
+
+    /// Returns a copy of the front once all elements previously sent through this handle have been pushed.
+    /// Returns `None` if the actor has been shut down.
+    ///
+    /// This operation blocks until the actor has processed the messages waiting before the request.
+    pub fn snapshot(&self) -> Option<ParetoFront<T, D>>
+        where T: Clone,
+              D: Clone
+    {
+        let (reply_sender, reply_receiver) = mpsc::sync_channel(1);
+        let query = Box::new(move |front: &ParetoFront<T, D>| {
+            // the requester might have given up on the answer
+            let _ = reply_sender.send(front.clone());
+        });
+        {
+            let _guard = self.start_send()?;
+            self.sender.send(Message::Query(query)).ok()?;
+        }
+        reply_receiver.recv().ok()
+    }
+
+    /// Asks the actor to stop once the messages already sent (from any handle) have been processed.
+    /// Fails if the actor is already stopping.
+    ///
+    /// Later operations on the handles of the actor fail,
+    /// the front can be recovered with `ParetoFrontActor::shutdown`.
+    pub fn shutdown(&self) -> Result<(), SendError<()>>
+    {
+        let _guard = self.start_send().ok_or(SendError(()))?;
+        self.sender.send(Message::Shutdown).map_err(|_| SendError(()))
+    }
+}
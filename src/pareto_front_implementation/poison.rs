@@ -0,0 +1,16 @@
+use std::sync::{LockResult, PoisonError};
+
+/// Returns the content of a lock (guard or value) even if the lock is poisoned.
+///
+/// A lock protecting a front gets poisoned when a thread panics while holding it, typically in the comparator.
+/// If the panic happens in the middle of a `push`, the elements dominated by the new element
+/// might already have been removed while the new element has not been inserted yet.
+/// The front is thus still a valid Pareto front (no element dominates another)
+/// but it might be missing elements: the ones that were removed and the one that was being pushed.
+///
+/// We consider this preferable to propagating the panic to every thread using the front,
+/// and thus keep using the front.
+pub(crate) fn ignore_poison<G>(result: LockResult<G>) -> G
+{
+    result.unwrap_or_else(PoisonError::into_inner)
+}
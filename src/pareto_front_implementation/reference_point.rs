@@ -0,0 +1,224 @@
+use crate::{Dominance, FrontObserver, Objectives, ParetoFront};
+#[cfg(feature = "pareto_front_concurrent")]
+use crate::ConcurrentParetoFront;
+
+/// Describes a region of interest in objective space.
+///
+/// The region is defined by aspiration levels (one per objective, the values one would be happy to reach)
+/// and a threshold on the distance to those aspiration levels.
+///
+/// The distance of an element to the reference point only takes into account the objectives
+/// on which the element *falls short* of its aspiration level (objectives being minimized).
+/// As a consequence, an element that dominates another element is always at least as close to the reference point
+/// which insures that filtering a Pareto front with a reference point still produces a valid Pareto front.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferencePoint
+{
+    aspiration_levels: Vec<f64>,
+    weights: Vec<f64>,
+    threshold: f64
+}
+
+impl ReferencePoint
+{
+    /// Constructs a new reference point from its aspiration levels
+    /// and the maximum distance an element can be from them to be kept in the front.
+    ///
+    /// All objectives are given a weight of `1`.
+    ///
+    /// Panics if `threshold` is negative or NaN.
+    pub fn new(aspiration_levels: Vec<f64>, threshold: f64) -> Self
+    {
+        assert!(threshold >= 0., "threshold should be non-negative");
+        let weights = vec![1.; aspiration_levels.len()];
+        ReferencePoint { aspiration_levels, weights, threshold }
+    }
+
+    /// Sets the weight of each objective in the distance computation.
+    ///
+    /// This is useful to put objectives with different scales on an equal footing.
+    ///
+    /// Panics if there is not exactly one weight per aspiration level
+    /// or if a weight is negative or not finite (the distance would not be consistent with dominance anymore).
+    pub fn with_weights(mut self, weights: Vec<f64>) -> Self
+    {
+        assert_eq!(weights.len(), self.aspiration_levels.len(), "expected one weight per objective");
+        assert!(weights.iter().all(|weight| weight.is_finite() && (*weight >= 0.)),
+                "weights should be finite and non-negative");
+        self.weights = weights;
+        self
+    }
+
+    /// Returns the aspiration levels of the reference point.
+    pub fn aspiration_levels(&self) -> &[f64]
+    {
+        &self.aspiration_levels
+    }
+
+    /// Returns the maximum distance an element can be from the reference point to be kept in the front.
+    pub fn threshold(&self) -> f64
+    {
+        self.threshold
+    }
+
+    /// Returns the weighted euclidean distance between `element` and the aspiration levels,
+    /// only taking into account objectives on which `element` is worse than its aspiration level.
+    pub fn distance<T: Objectives>(&self, element: &T) -> f64
+    {
+        self.aspiration_levels
+            .iter()
+            .zip(self.weights.iter())
+            .enumerate()
+            .map(|(index, (aspiration, weight))| {
+                let shortfall = (element.objective(index) - aspiration).max(0.);
+                weight * shortfall * shortfall
+            })
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Returns `true` if `element` is within the region of interest defined by the reference point.
+    pub fn contains<T: Objectives>(&self, element: &T) -> bool
+    {
+        self.distance(element) <= self.threshold
+    }
+}
+
+/// Wraps a Pareto front such that it only keeps elements that are close enough to a reference point.
+///
+/// This lets one focus the front on a region of interest (a form of reference-point-based dominance)
+/// and works with both `ParetoFront` and `ConcurrentParetoFront`.
+///
+/// ```rust
+/// # use pareto_front::{Dominate, Objectives, ParetoFront, ReferencePoint, ReferencePointFront};
+/// #
+/// # #[derive(PartialEq)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: f64, // to be minimized
+/// #     latency: f64, // to be minimized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.latency <= x.latency) && (self != x)
+/// #     }
+/// # }
+/// #
+/// # impl Objectives for ParetoElement
+/// # {
+/// #     fn nb_objectives(&self) -> usize
+/// #     {
+/// #         2
+/// #     }
+/// #
+/// #     fn objective(&self, index: usize) -> f64
+/// #     {
+/// #         if index == 0 { self.cost } else { self.latency }
+/// #     }
+/// # }
+/// #
+/// // we are interested in a cost of 10 and a latency of 1 but are ready to deviate a bit from it
+/// let reference_point = ReferencePoint::new(vec![10., 1.], 5.);
+/// let mut front: ReferencePointFront<ParetoFront<_>> = ReferencePointFront::new(reference_point);
+///
+/// // too far from the reference point
+/// assert!(!front.push(ParetoElement { cost: 100., latency: 0.5 }));
+/// // close enough
+/// assert!(front.push(ParetoElement { cost: 12., latency: 2. }));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ReferencePointFront<F>
+{
+    front: F,
+    reference_point: ReferencePoint
+}
+
+impl<F: Default> ReferencePointFront<F>
+{
+    /// Constructs a new, empty, front focused on `reference_point`.
+    pub fn new(reference_point: ReferencePoint) -> Self
+    {
+        ReferencePointFront { front: F::default(), reference_point }
+    }
+}
+
+impl<F> ReferencePointFront<F>
+{
+    /// Returns the reference point currently used to filter the front.
+    pub fn reference_point(&self) -> &ReferencePoint
+    {
+        &self.reference_point
+    }
+
+    /// Returns a reference to the underlying front.
+    pub fn inner(&self) -> &F
+    {
+        &self.front
+    }
+
+    /// Returns the underlying front.
+    pub fn into_inner(self) -> F
+    {
+        self.front
+    }
+}
+
+impl<T: Objectives, D: Dominance<T>, O: FrontObserver<T>> ReferencePointFront<ParetoFront<T, D, O>>
+{
+    /// Wraps an existing front, removing its elements that are too far from `reference_point`.
+    pub fn from_front(mut front: ParetoFront<T, D, O>, reference_point: ReferencePoint) -> Self
+    {
+        front.retain(|x| reference_point.contains(x));
+        ReferencePointFront { front, reference_point }
+    }
+
+    /// Adds `new_element` to the front if it is close enough to the reference point.
+    /// Returns `true` if the element is now in the front.
+    /// Returns `false` if the element was too far from the reference point or dominated.
+    ///
+    /// This operation has the complexity of `ParetoFront::push`.
+    pub fn push(&mut self, new_element: T) -> bool
+    {
+        self.reference_point.contains(&new_element) && self.front.push(new_element)
+    }
+
+    /// Replaces the reference point, removing the elements of the front that are too far from the new one.
+    ///
+    /// Note that elements previously rejected for being too far from the old reference point are *not* recovered.
+    ///
+    /// This operation has `O(n)` complexity where `n` is the number of elements currently in the front.
+    pub fn set_reference_point(&mut self, reference_point: ReferencePoint)
+    {
+        self.front.retain(|x| reference_point.contains(x));
+        self.reference_point = reference_point;
+    }
+}
+
+#[cfg(feature = "pareto_front_concurrent")]
+impl<T: Objectives + Send, D: Dominance<T> + Clone + Send, O: FrontObserver<T> + Clone + Send>
+    ReferencePointFront<ConcurrentParetoFront<T, D, O>>
+{
+    /// Adds `new_element` to the front if it is close enough to the reference point.
+    /// Returns `true` if the element *might be* in the front.
+    /// Returns `false` if the element was too far from the reference point or dominated.
+    ///
+    /// This operation has the complexity of `ConcurrentParetoFront::push`
+    /// and is meant to be called in parallel.
+    pub fn push(&self, new_element: T) -> bool
+    {
+        self.reference_point.contains(&new_element) && self.front.push(new_element)
+    }
+
+    /// Replaces the reference point, removing the elements of all thread-local fronts that are too far from the new one.
+    ///
+    /// Note that elements previously rejected for being too far from the old reference point are *not* recovered.
+    pub fn set_reference_point(&mut self, reference_point: ReferencePoint)
+    {
+        self.front.retain(|x| reference_point.contains(x));
+        self.reference_point = reference_point;
+    }
+}
@@ -0,0 +1,252 @@
+//! Relaxed dominance relations, useful to keep a meaningful front with a large number of objectives.
+//!
+//! With many objectives, most elements end up being non-dominated and the Pareto front grows to contain nearly everything.
+//! The relations defined here are all implied by Pareto dominance (an element that Pareto-dominates another still dominates it)
+//! but let more elements be dominated, keeping the front small.
+use crate::{Dominance, Objectives, ParetoFront};
+
+/// k-dominance, as introduced by Farina and Amato.
+///
+/// `x` k-dominates `y` if they differ on at least one objective
+/// and `x` is better than `y` on at least `(m - e) / (k + 1)` objectives
+/// where `m` is the number of objectives and `e` the number of objectives on which they are equal.
+///
+/// `k` is in `[0, 1]`, `0` giving back Pareto dominance and `1` being the most relaxed.
+/// Note that this relation is not transitive when `k` is not `0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KDominance
+{
+    k: f64
+}
+
+impl KDominance
+{
+    /// Constructs a new k-dominance relation.
+    ///
+    /// Panics if `k` is not in `[0, 1]`.
+    pub fn new(k: f64) -> Self
+    {
+        assert!((0. ..=1.).contains(&k), "k should be in [0, 1]");
+        KDominance { k }
+    }
+}
+
+impl<T: Objectives> Dominance<T> for KDominance
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        let nb_objectives = x.nb_objectives();
+        let mut nb_better = 0;
+        let mut nb_equal = 0;
+        for index in 0..nb_objectives
+        {
+            let (xo, yo) = (x.objective(index), y.objective(index));
+            if xo < yo
+            {
+                nb_better += 1;
+            }
+            else if xo == yo
+            {
+                nb_equal += 1;
+            }
+        }
+        (nb_equal < nb_objectives) && (nb_better as f64 >= (nb_objectives - nb_equal) as f64 / (self.k + 1.))
+    }
+
+    fn is_transitive(&self) -> bool
+    {
+        self.k == 0.
+    }
+}
+
+/// α-dominance, as introduced by Ikeda, Kita and Kobayashi.
+///
+/// Each objective is replaced by itself plus `alpha` times the sum of the other objectives
+/// before checking for Pareto dominance,
+/// letting a large improvement on some objectives compensate for a small deterioration on another one.
+///
+/// `alpha` is expected to be non-negative, `0` giving back Pareto dominance.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AlphaDominance
+{
+    alpha: f64
+}
+
+impl AlphaDominance
+{
+    /// Constructs a new α-dominance relation.
+    ///
+    /// Panics if `alpha` is negative.
+    pub fn new(alpha: f64) -> Self
+    {
+        assert!(alpha >= 0., "alpha should be non-negative");
+        AlphaDominance { alpha }
+    }
+}
+
+impl<T: Objectives> Dominance<T> for AlphaDominance
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        let nb_objectives = x.nb_objectives();
+        let total_difference: f64 =
+            (0..nb_objectives).map(|index| x.objective(index) - y.objective(index)).sum();
+        let mut is_strictly_better = false;
+        for index in 0..nb_objectives
+        {
+            let difference = x.objective(index) - y.objective(index);
+            let tradeoff = difference + self.alpha * (total_difference - difference);
+            if tradeoff > 0.
+            {
+                return false;
+            }
+            is_strictly_better |= tradeoff < 0.;
+        }
+        is_strictly_better
+    }
+}
+
+/// Grid dominance, as used by the grid-based evolutionary algorithm of Yang et al.
+///
+/// The objective space is divided into a grid with `divisions` cells along each objective
+/// and `x` dominates `y` if the cell of `x` Pareto-dominates the cell of `y`.
+/// Elements sharing a cell are compared with Pareto dominance.
+///
+/// Elements are expected to have one objective per bound of the grid (checked in debug mode).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GridDominance
+{
+    lower_bounds: Vec<f64>,
+    cell_sizes: Vec<f64>,
+    divisions: usize
+}
+
+impl GridDominance
+{
+    /// Constructs a new grid dominance relation on the box delimited by `lower_bounds` and `upper_bounds`.
+    ///
+    /// Elements outside of the box are put in the cells at its border.
+    ///
+    /// Panics if the bounds do not have the same length, are not finite,
+    /// if an upper bound is not strictly larger than its lower bound or if `divisions` is zero.
+    pub fn new(lower_bounds: Vec<f64>, upper_bounds: Vec<f64>, divisions: usize) -> Self
+    {
+        assert_eq!(lower_bounds.len(), upper_bounds.len(), "expected one upper bound per lower bound");
+        assert!(lower_bounds.iter()
+                            .zip(upper_bounds.iter())
+                            .all(|(lb, ub)| lb.is_finite() && ub.is_finite() && (ub > lb)),
+                "expected finite bounds with each upper bound larger than its lower bound");
+        assert!(divisions > 0, "expected at least one division");
+        let cell_sizes = lower_bounds.iter()
+                                     .zip(upper_bounds.iter())
+                                     .map(|(lb, ub)| (ub - lb) / divisions as f64)
+                                     .collect();
+        GridDominance { lower_bounds, cell_sizes, divisions }
+    }
+
+    /// Returns the coordinate, along objective `index`, of the cell containing `x`.
+    fn cell<T: Objectives>(&self, x: &T, index: usize) -> f64
+    {
+        let last_cell = (self.divisions - 1) as f64;
+        let position = (x.objective(index) - self.lower_bounds[index]) / self.cell_sizes[index];
+        position.floor().clamp(0., last_cell)
+    }
+}
+
+impl<T: Objectives> Dominance<T> for GridDominance
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        debug_assert_eq!(x.nb_objectives(),
+                         self.lower_bounds.len(),
+                         "expected one objective per bound of the grid");
+        debug_assert_eq!(y.nb_objectives(),
+                         self.lower_bounds.len(),
+                         "expected one objective per bound of the grid");
+        let mut same_cell = true;
+        let mut is_better_or_equal = true;
+        let mut is_strictly_better = false;
+        for index in 0..self.lower_bounds.len()
+        {
+            let (xc, yc) = (self.cell(x, index), self.cell(y, index));
+            if xc > yc
+            {
+                return false;
+            }
+            same_cell &= xc == yc;
+            let (xo, yo) = (x.objective(index), y.objective(index));
+            is_better_or_equal &= xo <= yo;
+            is_strictly_better |= xo < yo;
+        }
+        // elements sharing a cell fall back to Pareto dominance
+        !same_cell || (is_better_or_equal && is_strictly_better)
+    }
+}
+
+/// Fuzzy dominance, in the spirit of the fuzzy-Pareto-dominance of Köppen et al.
+///
+/// For each objective, `x` is considered at least as good as `y` with a degree of
+/// `1` if `x` is better or equal, `0` if `x` is worse by more than `tolerance` and linearly interpolated in between.
+/// The degree to which `x` dominates `y` is the product of those per-objective degrees.
+///
+/// `x` dominates `y` if this degree is at least `threshold` and larger than the degree to which `y` dominates `x`.
+/// Note that this relation is not transitive.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FuzzyDominance
+{
+    tolerance: f64,
+    threshold: f64
+}
+
+impl FuzzyDominance
+{
+    /// Constructs a new fuzzy dominance relation.
+    ///
+    /// Panics if `tolerance` is not positive or `threshold` is not in `]0, 1]`.
+    pub fn new(tolerance: f64, threshold: f64) -> Self
+    {
+        assert!(tolerance > 0., "tolerance should be positive");
+        assert!((threshold > 0.) && (threshold <= 1.), "threshold should be in ]0, 1]");
+        FuzzyDominance { tolerance, threshold }
+    }
+
+    /// Returns the degree to which `x` dominates `y`, between `0` and `1`.
+    pub fn degree<T: Objectives>(&self, x: &T, y: &T) -> f64
+    {
+        (0..x.nb_objectives()).map(|index| {
+                                  let deterioration = x.objective(index) - y.objective(index);
+                                  (1. - deterioration / self.tolerance).clamp(0., 1.)
+                              })
+                              .product()
+    }
+}
+
+impl<T: Objectives> Dominance<T> for FuzzyDominance
+{
+    fn dominate(&self, x: &T, y: &T) -> bool
+    {
+        let degree = self.degree(x, y);
+        (degree >= self.threshold) && (degree > self.degree(y, x))
+    }
+
+    fn is_transitive(&self) -> bool
+    {
+        false
+    }
+}
+
+/// Pareto front using k-dominance as its dominance relation.
+pub type KDominanceFront<T> = ParetoFront<T, KDominance>;
+
+/// Pareto front using α-dominance as its dominance relation.
+pub type AlphaDominanceFront<T> = ParetoFront<T, AlphaDominance>;
+
+/// Pareto front using grid dominance as its dominance relation.
+pub type GridDominanceFront<T> = ParetoFront<T, GridDominance>;
+
+/// Pareto front using fuzzy dominance as its dominance relation.
+pub type FuzzyDominanceFront<T> = ParetoFront<T, FuzzyDominance>;
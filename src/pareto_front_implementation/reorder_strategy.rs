@@ -0,0 +1,96 @@
+use super::members::Members;
+
+/// How a `ParetoFront` reorders its elements when one of them dominates a candidate.
+///
+/// Putting the elements that dominate many candidates at the top of the front lets later insertions stop earlier.
+/// Which strategy works best depends on the distribution of the elements, see `benches/reorder.rs`.
+///
+/// ```rust
+/// # use pareto_front::{Dominate, ParetoFront, ReorderStrategy};
+/// #
+/// # #[derive(PartialEq)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// let mut front = ParetoFront::new().with_reorder_strategy(ReorderStrategy::MoveToFront);
+/// front.push(ParetoElement { cost: 35, quality: 0.5 });
+/// front.push(ParetoElement { cost: 5, quality: 0.25 });
+/// front.push(ParetoElement { cost: 10, quality: 0.1 }); // dominated by the second element
+///
+/// // the element that dominated the candidate is now first
+/// assert_eq!(front.as_slice()[0].cost, 5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReorderStrategy
+{
+    /// Elements are never moved (they can still be reordered when an element is removed).
+    None,
+    /// The element is swapped with the previous element, slowly percolating the best elements to the top.
+    #[default]
+    SwapWithPrevious,
+    /// The element is moved to the top of the front.
+    MoveToFront,
+    /// Elements are kept sorted by the number of candidates they dominated.
+    ///
+    /// To keep them sorted, removing an element shifts all the following elements, in `O(n)`.
+    CountBased
+}
+
+impl ReorderStrategy
+{
+    /// Reorders `front` knowing that its element at `index` just dominated a candidate.
+    #[inline(always)]
+    pub(crate) fn promote<T>(self, front: &mut Members<T>, index: usize)
+    {
+        // the default strategy is handled inline as it is on the hot path of insertions
+        if self == ReorderStrategy::SwapWithPrevious
+        {
+            if index > 0
+            {
+                front.swap(index, index - 1);
+            }
+        }
+        else
+        {
+            self.promote_slow(front, index);
+        }
+    }
+
+    /// Reorders `front` for the strategies other than `SwapWithPrevious`.
+    #[inline(never)]
+    fn promote_slow<T>(self, front: &mut Members<T>, index: usize)
+    {
+        match self
+        {
+            ReorderStrategy::None => (),
+            ReorderStrategy::SwapWithPrevious =>
+            {
+                if index > 0
+                {
+                    front.swap(index, index - 1);
+                }
+            }
+            ReorderStrategy::MoveToFront =>
+            {
+                // a sequence of swaps (rather than a rotation) keeps the identifiers of the elements up to date
+                for i in (1..=index).rev()
+                {
+                    front.swap(i, i - 1);
+                }
+            }
+            ReorderStrategy::CountBased => front.record_kill(index)
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{Dominance, Dominate, NaturalDominance, ParetoFront};
+use super::poison::ignore_poison;
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::iter::FromIterator;
+
+/// Represents a single Pareto front, shared between threads, that can be pushed into concurrently.
+///
+/// Unlike `ConcurrentParetoFront`, which keeps one front per thread,
+/// all threads push into the same front: the result of `push` is thus definitive,
+/// an element rejected by one thread is dominated by an element that all threads can see.
+///
+/// The front is protected by a `RwLock`.
+/// Candidates are first checked under a read lock, which lets threads reject dominated candidates concurrently,
+/// the write lock is only taken to insert candidates that were not found dominated.
+/// This works best when most candidates are dominated (which is typical of an optimization that is converging),
+/// if most candidates are inserted, one would get better performance with a `ConcurrentParetoFront`.
+///
+/// Candidates rejected under the read lock do not reorder the front
+/// and, if the `pareto_front_stats` feature is enabled, are not counted in its statistics.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_concurrent` feature.**
+///
+/// ```rust
+/// # use pareto_front::{Dominate, SharedParetoFront};
+/// #
+/// # #[derive(PartialEq)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// let front = SharedParetoFront::new();
+/// std::thread::scope(|scope| {
+///     scope.spawn(|| front.push(ParetoElement { cost: 35, quality: 0.5 }));
+///     scope.spawn(|| front.push(ParetoElement { cost: 5, quality: 0.25 }));
+/// });
+///
+/// // dominated by an element pushed by another thread
+/// assert!(!front.push(ParetoElement { cost: 50, quality: 0.25 }));
+/// assert_eq!(front.len(), 2);
+/// ```
+#[derive(Debug)]
+pub struct SharedParetoFront<T, D = NaturalDominance>
+{
+    front: RwLock<ParetoFront<T, D>>
+}
+
+impl<T: Dominate> SharedParetoFront<T>
+{
+    /// Constructs a new, empty, shared Pareto front.
+    pub fn new() -> Self
+    {
+        SharedParetoFront { front: RwLock::new(ParetoFront::new()) }
+    }
+}
+
+impl<T, D: Dominance<T>> SharedParetoFront<T, D>
+{
+    /// Constructs a new, empty, shared Pareto front using `dominance` as its dominance relation.
+    pub fn with_comparator(dominance: D) -> Self
+    {
+        SharedParetoFront { front: RwLock::new(ParetoFront::with_comparator(dominance)) }
+    }
+
+    /// Locks the front for reading, ignoring poisoning (see `ignore_poison` for what this implies).
+    fn read(&self) -> RwLockReadGuard<'_, ParetoFront<T, D>>
+    {
+        ignore_poison(self.front.read())
+    }
+
+    /// Locks the front for writing, ignoring poisoning.
+    fn write(&self) -> RwLockWriteGuard<'_, ParetoFront<T, D>>
+    {
+        ignore_poison(self.front.write())
+    }
+
+    /// Returns `true` if at least one element on the Pareto front dominates `new_element`.
+    ///
+    /// This operation has `O(n)` complexity (where `n` is the number of elements currently in the Pareto front)
+    /// and only takes a read lock.
+    pub fn dominate(&self, new_element: &T) -> bool
+    {
+        self.read().dominate(new_element)
+    }
+
+    /// Adds `new_element` to the Pareto front.
+    /// Returns `true` if the element is now in the Pareto front.
+    /// Returns `false` if the element was dominated and, thus, not added to the front.
+    ///
+    /// The result holds across all threads: when `false` is returned,
+    /// the front contains an element dominating `new_element` (or an element dominating that element).
+    ///
+    /// This operation has `O(n)` complexity (where `n` is the number of elements currently in the Pareto front).
+    /// It first looks for a dominating element under a read lock
+    /// and only takes the write lock if there is none.
+    pub fn push(&self, new_element: T) -> bool
+    {
+        if self.dominate(&new_element)
+        {
+            return false;
+        }
+        // the front might have changed between the two locks, `push` checks `new_element` again
+        self.write().push(new_element)
+    }
+
+    /// Returns the number of elements currently in the Pareto front.
+    pub fn len(&self) -> usize
+    {
+        self.read().len()
+    }
+
+    /// Returns `true` if the Pareto front contains no elements.
+    pub fn is_empty(&self) -> bool
+    {
+        self.read().is_empty()
+    }
+
+    /// Returns a copy of the current content of the Pareto front.
+    ///
+    /// This operation has `O(n)` complexity (where `n` is the number of elements currently in the Pareto front)
+    /// and only takes a read lock.
+    pub fn snapshot(&self) -> ParetoFront<T, D>
+        where T: Clone,
+              D: Clone
+    {
+        self.read().clone()
+    }
+
+    /// Turns the shared Pareto front into a, sequential, `ParetoFront`.
+    /// This operation has complexity `O(1)`.
+    pub fn into_sequential(self) -> ParetoFront<T, D>
+    {
+        ignore_poison(self.front.into_inner())
+    }
+}
+
+impl<T, D: Dominance<T> + Default> Default for SharedParetoFront<T, D>
+{
+    /// Default value.
+    fn default() -> Self
+    {
+        // Manually implemented so as to not require `T` to implement `Default`.
+        Self::with_comparator(D::default())
+    }
+}
+
+impl<T, D: Dominance<T>> From<SharedParetoFront<T, D>> for ParetoFront<T, D>
+{
+    /// Converts the shared Pareto front into a `ParetoFront`.
+    /// This operation has complexity `O(1)`.
+    fn from(front: SharedParetoFront<T, D>) -> ParetoFront<T, D>
+    {
+        front.into_sequential()
+    }
+}
+
+impl<T, D: Dominance<T>> From<SharedParetoFront<T, D>> for Vec<T>
+{
+    /// Converts the shared Pareto front into a vector.
+    /// This operation has complexity `O(1)`.
+    fn from(front: SharedParetoFront<T, D>) -> Vec<T>
+    {
+        front.into_sequential().into()
+    }
+}
+
+impl<T, D: Dominance<T>> From<ParetoFront<T, D>> for SharedParetoFront<T, D>
+{
+    /// Converts a `ParetoFront` into a shared Pareto front.
+    /// This operation has complexity `O(1)`.
+    fn from(front: ParetoFront<T, D>) -> Self
+    {
+        SharedParetoFront { front: RwLock::new(front) }
+    }
+}
+
+impl<T, D: Dominance<T> + Default> FromIterator<T> for SharedParetoFront<T, D>
+{
+    /// Implements the `FromIterator` trait to enable the collection of an iterator into a `SharedParetoFront`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+    {
+        let front: ParetoFront<T, D> = iter.into_iter().collect();
+        front.into()
+    }
+}
+
+impl<T, D: Dominance<T>> Extend<T> for SharedParetoFront<T, D>
+{
+    /// Implements the `Extend` trait to extend a `SharedParetoFront` with the content of an iterator.
+    ///
+    /// As we have exclusive access to the front, no lock is taken.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+    {
+        ignore_poison(self.front.get_mut()).extend(iter)
+    }
+}
@@ -0,0 +1,152 @@
+use crate::Dominance;
+
+/// Counters describing the work performed by a front.
+///
+/// Comparisons done by `ParetoFront::dominate` are not counted, only the ones done while inserting or merging elements.
+///
+/// **NOTE: This struct is only available when enabling the `pareto_front_stats` feature.**
+///
+/// ```rust
+/// # use pareto_front::{Dominate, ParetoFront};
+/// #
+/// # #[derive(PartialEq)]
+/// # struct ParetoElement
+/// # {
+/// #     cost: usize, // to be minimized
+/// #     quality: f32, // to be maximized
+/// # }
+/// #
+/// # impl Dominate for ParetoElement
+/// # {
+/// #     fn dominate(&self, x: &Self) -> bool
+/// #     {
+/// #         (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+/// #     }
+/// # }
+/// #
+/// let mut front = ParetoFront::new();
+/// front.push(ParetoElement { cost: 35, quality: 0.5 });
+/// front.push(ParetoElement { cost: 50, quality: 0.25 }); // dominated by the first element
+///
+/// assert_eq!(front.stats().early_rejections, 1);
+/// assert_eq!(front.stats().comparisons, 1);
+///
+/// front.reset_stats();
+/// assert_eq!(front.stats().comparisons, 0);
+/// ```
+#[cfg(feature = "pareto_front_stats")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrontStats
+{
+    /// Number of calls to the dominance relation.
+    pub comparisons: u64,
+    /// Number of candidates rejected as they were dominated by an element of the front,
+    /// stopping the scan of the front early.
+    pub early_rejections: u64,
+    /// Number of elements removed as they were dominated by a candidate, or by `ParetoFront::remove`.
+    ///
+    /// When merging, elements of either front can be the candidates.
+    /// Elements filtered out by a reference point are not counted.
+    pub evictions: u64,
+    /// Number of fronts merged into the front (including the thread-local fronts of a `ConcurrentParetoFront`).
+    pub merges: u64
+}
+
+#[cfg(feature = "pareto_front_stats")]
+impl FrontStats
+{
+    /// Sets all counters back to zero.
+    pub fn reset(&mut self)
+    {
+        *self = FrontStats::default();
+    }
+}
+
+#[cfg(feature = "pareto_front_stats")]
+impl std::ops::AddAssign for FrontStats
+{
+    /// Sums the counters of two fronts.
+    fn add_assign(&mut self, other: FrontStats)
+    {
+        self.comparisons += other.comparisons;
+        self.early_rejections += other.early_rejections;
+        self.evictions += other.evictions;
+        self.merges += other.merges;
+    }
+}
+
+/// Records the work performed by a front, in `FrontStats` if the `pareto_front_stats` feature is enabled.
+#[cfg(feature = "pareto_front_stats")]
+pub(crate) type StatsRecorder = FrontStats;
+
+/// Records the work performed by a front, does nothing as the `pareto_front_stats` feature is disabled.
+#[cfg(not(feature = "pareto_front_stats"))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct StatsRecorder;
+
+#[cfg(feature = "pareto_front_stats")]
+impl StatsRecorder
+{
+    /// Constructs a recorder with all counters at zero.
+    #[inline(always)]
+    pub(crate) fn new() -> Self
+    {
+        FrontStats::default()
+    }
+
+    /// Returns `dominance.dominate(x, y)`, counting the comparison.
+    #[inline(always)]
+    pub(crate) fn dominate<T, D: Dominance<T>>(&mut self, dominance: &D, x: &T, y: &T) -> bool
+    {
+        self.comparisons += 1;
+        dominance.dominate(x, y)
+    }
+
+    /// Counts a candidate rejected as it was dominated.
+    #[inline(always)]
+    pub(crate) fn record_rejection(&mut self)
+    {
+        self.early_rejections += 1;
+    }
+
+    /// Counts an element removed as it was dominated.
+    #[inline(always)]
+    pub(crate) fn record_eviction(&mut self)
+    {
+        self.evictions += 1;
+    }
+
+    /// Counts a merge.
+    #[inline(always)]
+    pub(crate) fn record_merge(&mut self)
+    {
+        self.merges += 1;
+    }
+}
+
+#[cfg(not(feature = "pareto_front_stats"))]
+impl StatsRecorder
+{
+    #[inline(always)]
+    pub(crate) fn new() -> Self
+    {
+        StatsRecorder
+    }
+
+    /// Returns `dominance.dominate(x, y)`.
+    #[inline(always)]
+    pub(crate) fn dominate<T, D: Dominance<T>>(&mut self, dominance: &D, x: &T, y: &T) -> bool
+    {
+        dominance.dominate(x, y)
+    }
+
+    #[inline(always)]
+    pub(crate) fn record_rejection(&mut self) {}
+
+    #[inline(always)]
+    pub(crate) fn record_eviction(&mut self) {}
+
+    #[inline(always)]
+    pub(crate) fn record_merge(&mut self) {}
+}
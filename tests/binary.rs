@@ -0,0 +1,152 @@
+#![cfg(feature = "pareto_front_binary")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{BinaryError, ParetoFront};
+
+/// builds a front to be saved
+fn sample_front() -> ParetoFront<ParetoElement>
+{
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    data.into_iter().collect()
+}
+
+/// saves a front to a file and loads it back
+#[test]
+fn save_load()
+{
+    let front = sample_front();
+    let path = std::env::temp_dir().join(format!("pareto_front_save_load_{}.bin", std::process::id()));
+    front.save(&path).unwrap();
+    let loaded_front = ParetoFront::<ParetoElement>::load(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(front, loaded_front.unwrap());
+
+    // empty fronts can also be saved
+    let empty_front = ParetoFront::<ParetoElement>::new();
+    let mut file = Vec::new();
+    empty_front.write_binary(&mut file).unwrap();
+    let loaded_front = ParetoFront::<ParetoElement>::read_binary(file.as_slice()).unwrap();
+    assert!(loaded_front.is_empty());
+}
+
+/// checks the header and how it evolves with versions
+#[test]
+fn header()
+{
+    let front = sample_front();
+    let mut file = Vec::new();
+    front.write_binary(&mut file).unwrap();
+    assert_eq!(&file[..8], b"PARETOFR");
+    assert_eq!(&file[8..16], &[1, 0, 0, 0, 12, 0, 0, 0]);
+    assert_eq!(&file[16..20], &3u32.to_le_bytes());
+    assert_eq!(&file[20..28], &(front.len() as u64).to_le_bytes());
+
+    // not a front
+    let mut corrupted_file = file.clone();
+    corrupted_file[0] = b'X';
+    let error = ParetoFront::<ParetoElement>::read_binary(corrupted_file.as_slice()).unwrap_err();
+    assert!(matches!(error, BinaryError::NotAFront));
+
+    // a later minor version adding a header field can be read
+    let mut minor_file = file.clone();
+    minor_file[10] = 1;
+    minor_file[12] = 16;
+    minor_file.splice(28..28, [0xFF; 4]);
+    let loaded_front = ParetoFront::<ParetoElement>::read_binary(minor_file.as_slice()).unwrap();
+    assert_eq!(front, loaded_front);
+
+    // a later major version cannot
+    let mut major_file = file.clone();
+    major_file[8] = 2;
+    let error = ParetoFront::<ParetoElement>::read_binary(major_file.as_slice()).unwrap_err();
+    assert!(matches!(error, BinaryError::UnsupportedVersion { major: 2, minor: 0 }), "{error}");
+
+    // wrong number of objectives
+    let mut objectives_file = file.clone();
+    objectives_file[16] = 2;
+    let error = ParetoFront::<ParetoElement>::read_binary(objectives_file.as_slice()).unwrap_err();
+    assert!(matches!(error, BinaryError::ObjectiveCountMismatch { expected: 2, found: 3 }), "{error}");
+
+    // truncated file
+    let error = ParetoFront::<ParetoElement>::read_binary(&file[..file.len() - 1]).unwrap_err();
+    assert!(matches!(error, BinaryError::Io(_)), "{error}");
+}
+
+/// loads a file in which an element is dominated by another one
+#[test]
+fn dominated_elements()
+{
+    // writes the elements of two fronts, one after the other, in a single file
+    let front = sample_front();
+    let dominated_element = ParetoElement { cost: usize::MAX, quality: 0, score: i64::MIN };
+    let mut corrupted_front: ParetoFront<ParetoElement> = ParetoFront::new();
+    corrupted_front.push(dominated_element);
+    let mut file = Vec::new();
+    front.write_binary(&mut file).unwrap();
+    let mut corrupted_file = Vec::new();
+    corrupted_front.write_binary(&mut corrupted_file).unwrap();
+    let nb_elements = front.len() as u64 + 1;
+    file[20..28].copy_from_slice(&nb_elements.to_le_bytes());
+    file.extend_from_slice(&corrupted_file[28..]);
+
+    // strict loading refuses the file
+    let error = ParetoFront::<ParetoElement>::read_binary(file.as_slice()).unwrap_err();
+    assert!(matches!(&error, BinaryError::DominatedElements(indices) if indices == &[front.len()]),
+            "{error}");
+
+    // repairing drops the dominated element
+    let repaired_front = ParetoFront::<ParetoElement>::read_binary_repair(file.as_slice()).unwrap();
+    assert_eq!(front, repaired_front);
+}
+
+/// element whose number of objectives varies
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct VariableElement(Vec<f64>);
+
+impl pareto_front::Dominate for VariableElement
+{
+    fn dominate(&self, x: &Self) -> bool
+    {
+        // only compares the objectives both elements have
+        self.0.iter().zip(&x.0).all(|(a, b)| a <= b) && (self != x)
+    }
+}
+
+impl pareto_front::Objectives for VariableElement
+{
+    fn nb_objectives(&self) -> usize
+    {
+        self.0.len()
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self.0[index]
+    }
+}
+
+/// a failed save leaves the previous file untouched
+#[test]
+fn failed_save()
+{
+    let path = std::env::temp_dir().join(format!("pareto_front_failed_save_{}.bin", std::process::id()));
+    let mut front = ParetoFront::new();
+    front.push(VariableElement(vec![1.0, 2.0]));
+    front.push(VariableElement(vec![2.0, 1.0]));
+    front.save(&path).unwrap();
+    let saved_file = std::fs::read(&path).unwrap();
+
+    // the last element has an unexpected number of objectives
+    front.push(VariableElement(vec![3.0, 0.0, 0.0]));
+    let error = front.save(&path).unwrap_err();
+    let file = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert!(matches!(error, BinaryError::ObjectiveCountMismatch { .. }), "{error}");
+    assert_eq!(file, saved_file);
+
+    // nothing is written to a writer either
+    let mut file = Vec::new();
+    assert!(front.write_binary(&mut file).is_err());
+    assert!(file.is_empty());
+}
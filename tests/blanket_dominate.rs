@@ -0,0 +1,68 @@
+#![cfg(feature = "pareto_front_blanket")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{Dominate, Forward, Maximize, ParetoFront};
+use std::rc::Rc;
+
+/// checks that arrays, vectors and tuples minimize all axes
+#[test]
+fn minimize_all_axes()
+{
+    assert!([1, 2, 3].dominate(&[1, 2, 4]));
+    assert!(![1, 2, 3].dominate(&[1, 2, 3]));
+    assert!(![1, 2, 3].dominate(&[0, 2, 4]));
+    assert!(vec![1., 2.].dominate(&vec![2., 2.]));
+    assert!(!vec![1., 2.].dominate(&vec![2., 2., 3.]));
+    assert!((1, 2., 'a').dominate(&(1, 2., 'b')));
+    assert!(!(1, 2., 'a').dominate(&(0, 2., 'b')));
+    assert!((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11).dominate(&(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 12)));
+}
+
+/// checks that `Maximize` flips the direction of an axis
+#[test]
+fn maximize_axis()
+{
+    assert!((1, Maximize(2)).dominate(&(1, Maximize(1))));
+    assert!(!(1, Maximize(1)).dominate(&(1, Maximize(2))));
+    assert!([Maximize(2.), Maximize(3.)].dominate(&[Maximize(1.), Maximize(3.)]));
+}
+
+/// checks that a front of tuples matches a front of the equivalent struct
+#[test]
+fn tuple_front()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // front of structs
+    let struct_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // front of tuples
+    let tuple_front: ParetoFront<_> =
+        data.iter().map(|x| (x.cost, Maximize(x.quality), Maximize(x.score))).collect();
+
+    // checks that both fronts contain the same elements
+    assert_eq!(struct_front.len(), tuple_front.len());
+    assert!(struct_front.iter()
+                        .all(|x| tuple_front.as_slice()
+                                            .contains(&(x.cost, Maximize(x.quality), Maximize(x.score)))));
+}
+
+/// checks that shared elements can be put in a front without being cloned
+#[test]
+fn shared_elements()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data: Vec<_> = ParetoElement::sample_n(1000, seed).into_iter().map(Rc::new).collect();
+
+    // fronts of references and shared pointers
+    let reference_front: ParetoFront<Forward<&ParetoElement>> =
+        data.iter().map(|x| Forward(x.as_ref())).collect();
+    let rc_front: ParetoFront<Forward<Rc<ParetoElement>>> = data.iter().cloned().map(Forward).collect();
+
+    // checks that both fronts contain the same elements
+    assert_eq!(reference_front.len(), rc_front.len());
+    assert!(rc_front.iter().all(|x| reference_front.as_slice().contains(&Forward(x.0.as_ref()))));
+}
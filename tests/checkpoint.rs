@@ -0,0 +1,102 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{ElementId, ParetoFront};
+use std::collections::HashMap;
+
+/// checks that replaying the diff on the previous state of the front gives its current state
+#[test]
+fn diff_replay()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(2000, seed);
+    let (first_half, second_half) = data.split_at(1000);
+
+    // fills the front with the first half of the data
+    let mut front = ParetoFront::new();
+    first_half.iter().for_each(|x| {
+                         front.push(*x);
+                     });
+    let checkpoint = front.checkpoint();
+    let mut before: HashMap<ElementId, ParetoElement> =
+        front.iter_with_ids().map(|(id, x)| (id, *x)).collect();
+
+    // fills the front with the second half of the data, half of it being merged
+    let (pushed, merged) = second_half.split_at(500);
+    pushed.iter().for_each(|x| {
+                     front.push(*x);
+                 });
+    front.merge(merged.iter().cloned().collect());
+
+    // applies the diff to the previous state
+    let diff = front.changes_since(checkpoint);
+    for id in diff.removed.iter()
+    {
+        assert!(before.remove(id).is_some());
+    }
+    for (id, x) in diff.added.iter()
+    {
+        assert!(before.insert(*id, **x).is_none());
+    }
+
+    let after: HashMap<ElementId, ParetoElement> = front.iter_with_ids().map(|(id, x)| (id, *x)).collect();
+    assert_eq!(before, after);
+}
+
+/// checks that elements added then removed between two checkpoints do not appear in the diff
+#[test]
+fn transient_elements()
+{
+    let mut front = ParetoFront::new();
+    front.push(ParetoElement { cost: 10, quality: 10, score: 10 });
+    let checkpoint = front.checkpoint();
+    assert!(front.changes_since(checkpoint).is_empty());
+
+    // added then dominated
+    front.push(ParetoElement { cost: 5, quality: 10, score: 10 });
+    front.push(ParetoElement { cost: 1, quality: 10, score: 10 });
+
+    let diff = front.changes_since(checkpoint);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.removed.len(), 1);
+    assert_eq!(diff.added[0].1.cost, 1);
+}
+
+/// checks that changes before a forgotten checkpoint are dropped while later changes are still available
+#[test]
+fn forget_changes()
+{
+    let mut front = ParetoFront::new();
+    let first = front.checkpoint();
+    front.push(ParetoElement { cost: 10, quality: 10, score: 10 });
+    let second = front.checkpoint();
+    front.push(ParetoElement { cost: 5, quality: 10, score: 10 });
+
+    front.forget_changes_before(second);
+    let diff = front.changes_since(second);
+    assert_eq!(diff.added.len(), 1);
+    assert_eq!(diff.removed.len(), 1);
+
+    let result = std::panic::catch_unwind(|| front.changes_since(first).is_empty());
+    assert!(result.is_err());
+}
+
+/// checks that checkpoints taken on another front, or on a clone, are refused
+#[test]
+fn foreign_checkpoint()
+{
+    let mut front = ParetoFront::new();
+    front.push(ParetoElement { cost: 10, quality: 10, score: 10 });
+    let _ = front.checkpoint();
+
+    let mut other_front = ParetoFront::new();
+    let other_checkpoint = other_front.checkpoint();
+    other_front.push(ParetoElement { cost: 5, quality: 10, score: 10 });
+    let result = std::panic::catch_unwind(|| front.changes_since(other_checkpoint).is_empty());
+    assert!(result.is_err());
+
+    let mut clone = front.clone();
+    let clone_checkpoint = clone.checkpoint();
+    let result = std::panic::catch_unwind(|| front.changes_since(clone_checkpoint).is_empty());
+    assert!(result.is_err());
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "pareto_front_cli")]
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// runs the `pareto` tool with the given arguments, feeding `input` to its standard input
+fn pareto(args: &[&str], input: &str) -> Output
+{
+    let mut child = Command::new(env!("CARGO_BIN_EXE_pareto")).args(args)
+                                                              .stdin(Stdio::piped())
+                                                              .stdout(Stdio::piped())
+                                                              .stderr(Stdio::piped())
+                                                              .spawn()
+                                                              .unwrap();
+    child.stdin.take().unwrap().write_all(input.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+/// returns the standard output of a successful run
+fn stdout(output: Output) -> String
+{
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).unwrap()
+}
+
+/// keeps the non-dominated rows, with all their columns, in their original order
+#[test]
+fn csv()
+{
+    let input = "name,cost,quality\n\
+                 first,35,0.5\n\
+                 second,350,0.05\n\
+                 third,5,0.25\n\
+                 fourth,5,0.25\n";
+    let output = pareto(&["--min", "cost", "--max", "quality"], input);
+    assert_eq!(stdout(output), "name,cost,quality\nfirst,35,0.5\nthird,5,0.25\nfourth,5,0.25\n");
+
+    // the direction of the objectives matters
+    let output = pareto(&["--max", "cost", "--max", "quality"], input);
+    assert_eq!(stdout(output), "name,cost,quality\nfirst,35,0.5\nsecond,350,0.05\n");
+}
+
+/// tables without headers, separated by tabs
+#[test]
+fn tsv()
+{
+    let input = "first\t35\t0.5\nsecond\t350\t0.05\nthird\t5\t0.25\n";
+    let output = pareto(&["--format", "tsv", "--no-header", "--min", "2", "--max", "3"], input);
+    assert_eq!(stdout(output), "first\t35\t0.5\nthird\t5\t0.25\n");
+}
+
+/// lines are written back unchanged
+#[test]
+fn json_lines()
+{
+    let input = "{\"name\": \"first\", \"cost\": 35, \"quality\": 0.5}\n\
+                 {\"name\": \"second\", \"cost\": 350, \"quality\": 0.05}\n\
+                 \n\
+                 {\"name\": \"third\", \"cost\": 5, \"quality\": 0.25}\n";
+    let output = pareto(&["--format", "jsonl", "--min", "cost", "--max", "quality"], input);
+    assert_eq!(stdout(output),
+               "{\"name\": \"first\", \"cost\": 35, \"quality\": 0.5}\n\
+                {\"name\": \"third\", \"cost\": 5, \"quality\": 0.25}\n");
+}
+
+/// reads several files
+#[test]
+fn files()
+{
+    let directory = std::env::temp_dir();
+    let path1 = directory.join(format!("pareto_cli_{}_1.csv", std::process::id()));
+    let path2 = directory.join(format!("pareto_cli_{}_2.csv", std::process::id()));
+    std::fs::write(&path1, "cost,quality\n35,0.5\n350,0.05\n").unwrap();
+    std::fs::write(&path2, "cost,quality\n5,0.25\n1,0.75\n").unwrap();
+    let output =
+        pareto(&["--min", "cost", "--max", "quality", path1.to_str().unwrap(), path2.to_str().unwrap()], "");
+    std::fs::remove_file(&path1).unwrap();
+    std::fs::remove_file(&path2).unwrap();
+    assert_eq!(stdout(output), "cost,quality\n1,0.75\n");
+}
+
+/// errors are reported with their line
+#[test]
+fn errors()
+{
+    // invalid value
+    let output = pareto(&["--min", "cost"], "cost\n1\n2\nthree\n");
+    assert_eq!(output.status.code(), Some(1));
+    let error = String::from_utf8(output.stderr).unwrap();
+    assert!(error.contains("-:4: 'three' is not a number"), "{error}");
+
+    // unknown column
+    let output = pareto(&["--min", "price"], "cost\n1\n");
+    assert_eq!(output.status.code(), Some(1));
+
+    // no objective
+    let output = pareto(&[], "cost\n1\n");
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,114 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{Dominate, ParetoFront};
+
+/// cost versus quality trade-off, ignoring the score
+fn cost_quality(x: &ParetoElement, y: &ParetoElement) -> bool
+{
+    (x.cost <= y.cost) && (x.quality >= y.quality) && ((x.cost < y.cost) || (x.quality > y.quality))
+}
+
+/// projection of a `ParetoElement` on the cost and quality objectives
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CostQuality
+{
+    cost: usize,
+    quality: u8
+}
+
+impl Dominate for CostQuality
+{
+    fn dominate(&self, x: &Self) -> bool
+    {
+        (self.cost <= x.cost) && (self.quality >= x.quality) && (self != x)
+    }
+}
+
+/// checks that a front using a comparator on a subset of the objectives
+/// is equivalent to a front of elements restricted to those objectives
+#[test]
+fn objective_subset()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // front using a comparator
+    let mut comparator_front = ParetoFront::with_comparator(cost_quality);
+    data.iter().for_each(|x| {
+                   comparator_front.push(*x);
+               });
+
+    // front on projected elements
+    let projected_front: ParetoFront<_> =
+        data.iter().map(|x| CostQuality { cost: x.cost, quality: x.quality }).collect();
+
+    // checks that both fronts contain the same elements
+    assert!(comparator_front.len() < ParetoFront::<ParetoElement>::from_iter(data.iter().cloned()).len());
+    assert_eq!(comparator_front.len(), projected_front.len());
+    assert!(comparator_front.iter().all(|x| projected_front.as_slice()
+                                                           .contains(&CostQuality { cost: x.cost,
+                                                                                    quality: x.quality })));
+}
+
+/// checks that the comparator is used when merging fronts
+#[test]
+fn merge_with_comparator()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential front
+    let mut seq_front = ParetoFront::with_comparator(cost_quality);
+    data.iter().for_each(|x| {
+                   seq_front.push(*x);
+               });
+    let mut seq_front: Vec<_> = seq_front.into();
+    seq_front.sort();
+
+    // merged fronts
+    let (data1, data2) = data.split_at(300);
+    let mut front1 = ParetoFront::with_comparator(cost_quality);
+    front1.extend(data1.iter().cloned());
+    let mut front2 = ParetoFront::with_comparator(cost_quality);
+    front2.extend(data2.iter().cloned());
+    front1.merge(front2);
+    let mut merged_front: Vec<_> = front1.into();
+    merged_front.sort();
+
+    // checks for equality
+    assert_eq!(seq_front, merged_front);
+}
+
+/// checks that the comparator is used by the concurrent front
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn concurrent_with_comparator()
+{
+    use pareto_front::ConcurrentParetoFront;
+    use rayon::prelude::*;
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential front
+    let mut seq_front = ParetoFront::with_comparator(cost_quality);
+    data.iter().for_each(|x| {
+                   seq_front.push(*x);
+               });
+    let mut seq_front: Vec<_> = seq_front.into();
+    seq_front.sort();
+
+    // concurrent front
+    let conc_front = ConcurrentParetoFront::with_comparator(cost_quality);
+    data.par_iter().for_each(|x| {
+                       conc_front.push(*x);
+                   });
+    let mut conc_front: Vec<_> = conc_front.into();
+    conc_front.sort();
+
+    // checks for equality
+    assert_eq!(seq_front, conc_front);
+}
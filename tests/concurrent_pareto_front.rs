@@ -72,3 +72,139 @@ fn push_concurrent()
     assert_eq!(seq_front.len(), conc_front.len());
     assert!(seq_front.eq(&conc_front));
 }
+
+/// takes snapshots while pushing concurrently
+/// checks that they do not consume the front and that the last one matches the sequential front
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn snapshot_concurrent()
+{
+    use pareto_front::ConcurrentParetoFront;
+    use rayon::prelude::*;
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential front
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // concurrent front, snapshotted regularly
+    let conc_front = ConcurrentParetoFront::new();
+    data.par_iter().enumerate().for_each(|(i, x)| {
+                                   conc_front.push(*x);
+                                   if i % 100 == 0
+                                   {
+                                       let snapshot = conc_front.snapshot();
+                                       assert!(snapshot.iter().all(|y| !snapshot.dominate(y)));
+                                   }
+                               });
+
+    // the front can still be queried and is unchanged by the snapshots
+    assert_eq!(conc_front.snapshot(), seq_front);
+    assert!(data.iter().all(|x| conc_front.dominate(x) == seq_front.dominate(x)));
+    assert_eq!(conc_front.into_sequential(), seq_front);
+}
+
+/// pushes elements in one thread and an element dominating all of them in another thread
+/// checks that compacting the front removes the dominated elements from the first thread-local front
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn compact_concurrent()
+{
+    use pareto_front::{ConcurrentParetoFront, FrontObserver};
+    use std::sync::{Arc, Barrier};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// counts the evicted elements across all thread-local fronts
+    #[derive(Clone, Default)]
+    struct EvictionCounter(Arc<AtomicUsize>);
+
+    impl FrontObserver<ParetoElement> for EvictionCounter
+    {
+        fn on_evict(&mut self, _element: &ParetoElement)
+        {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let best = ParetoElement { cost: 0, quality: u8::MAX, score: i64::MAX };
+
+    // one thread-local front per thread
+    // the barrier keeps both threads alive so that they do not share a thread-local front
+    let counter = EvictionCounter::default();
+    let conc_front = ConcurrentParetoFront::new().with_observer(counter.clone());
+    let barrier = Barrier::new(2);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+                 data.iter().for_each(|x| {
+                                conc_front.push(*x);
+                            });
+                 barrier.wait();
+             });
+        scope.spawn(|| {
+                 conc_front.push(best);
+                 barrier.wait();
+             });
+    });
+    let evicted_by_pushes = counter.0.load(Ordering::Relaxed);
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // all elements of the first thread-local front are dominated by the element of the second one
+    conc_front.compact();
+    assert_eq!(counter.0.load(Ordering::Relaxed), evicted_by_pushes + seq_front.len());
+    assert_eq!(conc_front.into_sequential().as_slice(), &[best]);
+}
+
+/// checks that, after `compact`, elements dominated by another thread are rejected early
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn compact_elites_concurrent()
+{
+    use pareto_front::{ConcurrentParetoFront, FrontObserver};
+    use std::sync::{Arc, Barrier};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// counts the elements accepted by any thread-local front
+    #[derive(Clone, Default)]
+    struct AcceptanceCounter(Arc<AtomicUsize>);
+
+    impl FrontObserver<ParetoElement> for AcceptanceCounter
+    {
+        fn on_accept(&mut self, _element: &ParetoElement)
+        {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let best = ParetoElement { cost: 0, quality: u8::MAX, score: i64::MAX };
+
+    // the second thread only starts pushing once the element of the first thread has been shared by `compact`
+    // the barrier keeps both threads alive so that they do not share a thread-local front
+    let counter = AcceptanceCounter::default();
+    let conc_front = ConcurrentParetoFront::new().with_observer(counter.clone());
+    let barrier = Barrier::new(2);
+    std::thread::scope(|scope| {
+        scope.spawn(|| {
+                 assert!(conc_front.push(best));
+                 conc_front.compact();
+                 barrier.wait();
+                 barrier.wait();
+             });
+        scope.spawn(|| {
+                 barrier.wait();
+                 assert!(data.iter().all(|x| !conc_front.push(*x)));
+                 barrier.wait();
+             });
+    });
+
+    // only `best` ever entered a thread-local front
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+    assert_eq!(conc_front.into_sequential().as_slice(), &[best]);
+}
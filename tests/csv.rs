@@ -0,0 +1,91 @@
+#![cfg(feature = "pareto_front_csv")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{CsvColumn, CsvErrorKind, CsvOptions, ParetoFront};
+
+/// writes a front to CSV and reads it back
+#[test]
+fn round_trip()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let front: ParetoFront<_> = data.iter().cloned().collect();
+
+    for options in [CsvOptions::default(), CsvOptions::default().with_headers(false).with_delimiter(b'\t')]
+    {
+        let mut file = Vec::new();
+        front.write_csv(&mut file, &options).unwrap();
+        let read_front: ParetoFront<ParetoElement> =
+            ParetoFront::from_csv_reader(file.as_slice(), &options).unwrap();
+        assert_eq!(front, read_front);
+    }
+}
+
+/// reads a file with dominated rows and columns that are not objectives
+#[test]
+fn column_selection()
+{
+    let file = "id,score,comment,quality,cost\n\
+                a,5,first,5,1\n\
+                b,0,second,9,5\n\
+                c,1,dominated by a,4,2\n";
+    let expected = vec![ParetoElement { cost: 1, quality: 5, score: 5 }, ParetoElement { cost: 5,
+                                                                                         quality: 9,
+                                                                                         score: 0 }];
+
+    // selected by name, the order of the columns does not matter as they are matched with the fields
+    let options = CsvOptions::default().with_columns(["cost", "quality", "score"]);
+    let front: ParetoFront<ParetoElement> = ParetoFront::from_csv_reader(file.as_bytes(), &options).unwrap();
+    let mut elements: Vec<_> = front.into();
+    elements.sort();
+    assert_eq!(elements, expected);
+
+    // selected by index, without headers, the columns are given in the order of the fields
+    let headerless_file = file.split_once('\n').unwrap().1;
+    let options = CsvOptions::default().with_headers(false).with_columns([4, 3, 1]);
+    let front: ParetoFront<ParetoElement> =
+        ParetoFront::from_csv_reader(headerless_file.as_bytes(), &options).unwrap();
+    let mut elements: Vec<_> = front.into();
+    elements.sort();
+    assert_eq!(elements, expected);
+}
+
+/// checks that errors point to the offending line
+#[test]
+fn errors()
+{
+    // malformed value
+    let file = "cost,quality,score\n1,5,5\n5,not a number,0\n";
+    let error =
+        ParetoFront::<ParetoElement>::from_csv_reader(file.as_bytes(), &CsvOptions::default()).unwrap_err();
+    assert_eq!(error.line, Some(3));
+    assert!(matches!(error.kind, CsvErrorKind::Csv(_)));
+
+    // missing field
+    let file = "cost,quality,score\n1,5,5\n5,9,0\n2,4\n";
+    let error =
+        ParetoFront::<ParetoElement>::from_csv_reader(file.as_bytes(), &CsvOptions::default()).unwrap_err();
+    assert_eq!(error.line, Some(4));
+
+    // unknown column
+    let options = CsvOptions::default().with_columns(["cost", "quality", "price"]);
+    let error = ParetoFront::<ParetoElement>::from_csv_reader(file.as_bytes(), &options).unwrap_err();
+    assert_eq!(error.line, Some(1));
+    assert!(matches!(error.kind, CsvErrorKind::MissingColumn(_)));
+    assert!(error.to_string().contains("'price'"), "{error}");
+}
+
+/// finds columns by name or index, with and without headers
+#[test]
+fn find_column()
+{
+    let headers = ["cost", "quality", "score"].as_slice();
+    assert_eq!(CsvColumn::from("quality").find(Some(headers)), Some(1));
+    assert_eq!(CsvColumn::from("price").find(Some(headers)), None);
+    assert_eq!(CsvColumn::from(2).find(Some(headers)), Some(2));
+    assert_eq!(CsvColumn::from(3).find(Some(headers)), None);
+    // without headers, names cannot be found and indices are not checked
+    assert_eq!(CsvColumn::from("quality").find(None), None);
+    assert_eq!(CsvColumn::from(3).find(None), Some(3));
+}
@@ -0,0 +1,65 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{ElementId, ParetoFront};
+
+/// checks that identifiers keep pointing to the same elements while the front is being reordered
+#[test]
+fn stable_ids()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(2000, seed);
+
+    // keeps track of the identifier of all elements that entered the front
+    let mut front = ParetoFront::new();
+    let mut cache: Vec<(ElementId, ParetoElement)> = Vec::new();
+    for x in data.iter()
+    {
+        if let Some(id) = front.push_with_id(*x)
+        {
+            cache.push((id, *x));
+        }
+    }
+
+    // all elements still in the front are found from their identifiers, the others are gone
+    let mut nb_found = 0;
+    for (id, x) in cache.iter()
+    {
+        match front.get(*id)
+        {
+            Some(element) =>
+            {
+                assert_eq!(element, x);
+                nb_found += 1;
+            }
+            None => assert!(!front.iter().any(|element| element == x))
+        }
+    }
+    assert_eq!(nb_found, front.len());
+}
+
+/// checks that a removed element cannot be found anymore and that its identifier is not reused
+#[test]
+fn remove_by_id()
+{
+    let mut front = ParetoFront::new();
+    let x = ParetoElement { cost: 10, quality: 10, score: 10 };
+    let y = ParetoElement { cost: 5, quality: 5, score: 5 };
+    let x_id = front.push_with_id(x).unwrap();
+    let y_id = front.push_with_id(y).unwrap();
+
+    assert_eq!(front.remove(x_id), Some(x));
+    assert_eq!(front.remove(x_id), None);
+    assert!(!front.contains_id(x_id));
+    assert_eq!(front.get(y_id), Some(&y));
+
+    // the slot freed by `x` is reused with a different identifier
+    let z = ParetoElement { cost: 1, quality: 1, score: 1 };
+    let z_id = front.push_with_id(z).unwrap();
+    assert_ne!(z_id, x_id);
+    assert!(!front.contains_id(x_id));
+    assert_eq!(front.get(z_id), Some(&z));
+
+    // dominated elements do not get an identifier
+    assert_eq!(front.push_with_id(ParetoElement { cost: 10, quality: 0, score: 0 }), None);
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "pareto_front_journal")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{JournalErrorKind, JournalObserver, ParetoFront};
+
+/// rebuilds a front from the journal of its accepted elements
+#[test]
+fn rebuild()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let mut front = ParetoFront::new().with_observer(JournalObserver::new(Vec::new()));
+    front.extend(data.iter().cloned());
+    front.observer_mut().flush().unwrap();
+
+    // evicted elements are in the journal but not in the rebuilt front
+    let journal = front.observer().get_ref();
+    let nb_lines = journal.iter().filter(|&&c| c == b'\n').count();
+    assert!(nb_lines > front.len());
+    let rebuilt_front: ParetoFront<ParetoElement> = ParetoFront::from_journal(journal.as_slice()).unwrap();
+    let sequential_front: ParetoFront<_> = data.iter().cloned().collect();
+    assert_eq!(rebuilt_front, sequential_front);
+
+    // an interrupted last write is ignored
+    let mut interrupted_journal = journal.clone();
+    interrupted_journal.extend_from_slice(br#"{"cost":0,"quali"#);
+    let rebuilt_front: ParetoFront<ParetoElement> =
+        ParetoFront::from_journal(interrupted_journal.as_slice()).unwrap();
+    assert_eq!(rebuilt_front, sequential_front);
+}
+
+/// crashes in the middle of a write, recovers the journal and keeps appending to it
+#[test]
+fn crash_recovery()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let (data1, data2) = data.split_at(500);
+    let path = std::env::temp_dir().join(format!("pareto_front_journal_{}.jsonl", std::process::id()));
+    let open = || {
+        std::fs::OpenOptions::new().read(true).write(true).create(true).truncate(false).open(&path).unwrap()
+    };
+
+    // first run, interrupted in the middle of a write
+    let mut front = ParetoFront::new().with_observer(JournalObserver::new(open()));
+    front.extend(data1.iter().cloned());
+    front.observer_mut().flush().unwrap();
+    drop(front);
+    let mut file = std::fs::OpenOptions::new().append(true).open(&path).unwrap();
+    std::io::Write::write_all(&mut file, br#"{"cost":0,"quali"#).unwrap();
+
+    // restart, the interrupted line is dropped and later elements are appended (and synced) after the last complete line
+    let mut file = open();
+    let front: ParetoFront<ParetoElement> = ParetoFront::recover_journal(&mut file).unwrap();
+    let mut front = front.with_observer(JournalObserver::new(file).with_sync(true));
+    front.extend(data2.iter().cloned());
+    front.observer_mut().flush().unwrap();
+    drop(front);
+
+    // second restart
+    let mut file = open();
+    let rebuilt_front = ParetoFront::<ParetoElement>::recover_journal(&mut file);
+    std::fs::remove_file(&path).unwrap();
+    let sequential_front: ParetoFront<_> = data.iter().cloned().collect();
+    assert_eq!(rebuilt_front.unwrap(), sequential_front);
+}
+
+/// compacts the journal down to the current front and keeps appending to it
+#[test]
+fn compact()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let (data1, data2) = data.split_at(500);
+    let mut front = ParetoFront::new().with_observer(JournalObserver::new(Vec::new()));
+    front.extend(data1.iter().cloned());
+
+    // the new journal only contains the current front
+    let previous_journal = front.compact_journal(Vec::new()).unwrap();
+    let compacted_journal = front.observer().get_ref();
+    assert!(compacted_journal.len() < previous_journal.len());
+    assert_eq!(compacted_journal.iter().filter(|&&c| c == b'\n').count(), front.len());
+
+    // later elements are appended to the new journal
+    front.extend(data2.iter().cloned());
+    front.observer_mut().flush().unwrap();
+    let journal = front.observer().get_ref();
+    let rebuilt_front: ParetoFront<ParetoElement> = ParetoFront::from_journal(journal.as_slice()).unwrap();
+    let sequential_front: ParetoFront<_> = data.iter().cloned().collect();
+    assert_eq!(rebuilt_front, sequential_front);
+}
+
+/// checks that errors point to the offending line
+#[test]
+fn errors()
+{
+    let journal = "{\"cost\":1,\"quality\":5,\"score\":5}\n\n{\"cost\":5,\"quality\":9}\n";
+    let error = ParetoFront::<ParetoElement>::from_journal(journal.as_bytes()).unwrap_err();
+    assert_eq!(error.line, Some(3));
+    assert!(matches!(error.kind, JournalErrorKind::Json(_)));
+}
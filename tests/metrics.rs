@@ -0,0 +1,84 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::ParetoFront;
+use pareto_front::metrics::{FrontRelation, compare, coverage};
+
+/// builds a front from the given data
+fn front_from(data: &[ParetoElement]) -> ParetoFront<ParetoElement>
+{
+    data.iter().cloned().collect()
+}
+
+/// checks that equality does not depend on the order of the elements
+#[test]
+fn order_independent_equality()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let front = front_from(&data);
+
+    // same elements, reversed order
+    let mut reversed: Vec<_> = front.clone().into();
+    reversed.reverse();
+    let reversed = front_from(&reversed);
+    assert_eq!(front, reversed);
+
+    // a front with an element less is a strict subset
+    let smaller = front_from(&reversed.as_slice()[1..]);
+    assert_ne!(front, smaller);
+    assert!(smaller.is_subset(&front));
+    assert!(!front.is_subset(&smaller));
+}
+
+/// checks that duplicated elements are counted when comparing fronts
+#[test]
+fn equality_with_duplicates()
+{
+    let x = ParetoElement { cost: 1, quality: 1, score: 0 };
+    let y = ParetoElement { cost: 2, quality: 2, score: 0 };
+    let front = front_from(&[x, x, y]);
+    assert_eq!(front.len(), 3);
+    assert_eq!(front, front_from(&[y, x, x]));
+    assert_ne!(front, front_from(&[x, y, y]));
+}
+
+/// checks that the union of two fronts is the front of the union of their elements
+#[test]
+fn union()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let (data1, data2) = data.split_at(400);
+
+    // union of two fronts
+    let front1 = front_from(data1);
+    let front2 = front_from(data2);
+    assert_eq!(front1.union(&front2), front_from(&data));
+    assert_eq!(front1.union(&front2), front2.union(&front1));
+}
+
+/// checks the coverage metric and the comparison of fronts
+#[test]
+fn coverage_and_comparison()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let (data1, data2) = data.split_at(100);
+    let full_front = front_from(&data);
+    let partial_front = front_from(data1);
+    let other_partial_front = front_from(data2);
+
+    // the full front covers everything
+    assert_eq!(coverage(&full_front, &partial_front), 1.);
+    assert!(coverage(&partial_front, &full_front) < 1.);
+    assert_eq!(coverage(&full_front, &full_front), 1.);
+
+    // comparisons
+    assert_eq!(compare(&full_front, &full_front), FrontRelation::Equal);
+    assert_eq!(compare(&full_front, &partial_front), FrontRelation::Better);
+    assert_eq!(compare(&partial_front, &full_front), FrontRelation::Worse);
+    assert_eq!(compare(&partial_front, &other_partial_front), FrontRelation::Incomparable);
+}
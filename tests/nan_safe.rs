@@ -0,0 +1,77 @@
+use pareto_front::nan_safe::{NanPanic, NanReject, NanWorst, nan_safe_dominate};
+use pareto_front::{Dominate, Objectives, ParetoFront};
+
+/// 2D element whose objectives might be NaN
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Simulation
+{
+    objectives: [f64; 2]
+}
+
+impl Objectives for Simulation
+{
+    fn nb_objectives(&self) -> usize
+    {
+        2
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self.objectives[index]
+    }
+}
+
+impl Dominate for Simulation
+{
+    fn dominate(&self, x: &Self) -> bool
+    {
+        nan_safe_dominate(self, x)
+    }
+}
+
+/// element with a NaN objective
+const BROKEN: Simulation = Simulation { objectives: [f64::NAN, 1.] };
+/// element that is better than `BROKEN` on its non-NaN objective
+const VALID: Simulation = Simulation { objectives: [2., 0.5] };
+
+/// checks that NaN are considered as the worst possible value
+#[test]
+fn nan_is_worst()
+{
+    let mut front = ParetoFront::new();
+    assert!(front.push(NanWorst(BROKEN)));
+    assert!(front.push(NanWorst(VALID))); // kills the NaN element
+    assert!(!front.push(NanWorst(BROKEN)));
+    assert_eq!(front.as_slice(), &[NanWorst(VALID)]);
+
+    // `try_push` accepts NaN when they are considered worst
+    assert_eq!(front.try_push(NanWorst(BROKEN)), Ok(false));
+}
+
+/// checks that `try_push` refuses elements with a NaN objective by default
+#[test]
+fn try_push_rejects_nan()
+{
+    let mut front = ParetoFront::new();
+    let error = front.try_push(BROKEN).unwrap_err();
+    assert_eq!(error.objective, 0);
+    assert!(error.element.objectives[0].is_nan()); // the element is given back
+    assert!(front.is_empty());
+    assert_eq!(front.try_push(VALID), Ok(true));
+
+    // the explicit wrapper behaves the same way
+    let mut front = ParetoFront::new();
+    assert!(front.try_push(NanReject(BROKEN)).is_err());
+    assert_eq!(front.try_push(NanReject(VALID)), Ok(true));
+}
+
+/// checks that NaN trigger a panic in debug mode with the corresponding policy
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic]
+fn nan_panics()
+{
+    let mut front = ParetoFront::new();
+    front.push(NanPanic(VALID));
+    front.push(NanPanic(BROKEN));
+}
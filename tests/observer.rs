@@ -0,0 +1,124 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{FrontObserver, ParetoFront};
+use std::sync::{Arc, Mutex};
+
+/// records all the events reported by a front
+/// shares its state between clones so that it can be used with concurrent fronts
+#[derive(Clone, Default)]
+struct Recorder
+{
+    events: Arc<Mutex<Events>>
+}
+
+#[derive(Default)]
+struct Events
+{
+    accepted: Vec<ParetoElement>,
+    rejected: Vec<ParetoElement>,
+    evicted: Vec<ParetoElement>
+}
+
+impl FrontObserver<ParetoElement> for Recorder
+{
+    fn on_accept(&mut self, element: &ParetoElement)
+    {
+        self.events.lock().unwrap().accepted.push(*element);
+    }
+
+    fn on_reject(&mut self, element: &ParetoElement)
+    {
+        self.events.lock().unwrap().rejected.push(*element);
+    }
+
+    fn on_evict(&mut self, element: &ParetoElement)
+    {
+        self.events.lock().unwrap().evicted.push(*element);
+    }
+}
+
+impl Recorder
+{
+    /// checks that the events are consistent with the content of the front and the number of elements submitted
+    fn check(&self, front: &[ParetoElement], nb_submitted: usize)
+    {
+        let events = self.events.lock().unwrap();
+        assert_eq!(events.accepted.len() + events.rejected.len(), nb_submitted);
+
+        // elements accepted and not evicted are exactly the elements of the front
+        let mut remaining = events.accepted.clone();
+        for evicted in events.evicted.iter()
+        {
+            let index = remaining.iter()
+                                 .position(|x| x == evicted)
+                                 .expect("evicted an element that was not accepted");
+            remaining.swap_remove(index);
+        }
+        remaining.sort();
+        let mut front = front.to_vec();
+        front.sort();
+        assert_eq!(remaining, front);
+    }
+}
+
+/// checks that pushing reports every element
+#[test]
+fn observe_push()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let recorder = Recorder::default();
+    let mut front = ParetoFront::new().with_observer(recorder.clone());
+    front.extend(data.iter().cloned());
+
+    recorder.check(front.as_slice(), data.len());
+}
+
+/// checks that merging reports every element, whichever front is larger
+#[test]
+fn observe_merge()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    for split in [100, 900]
+    {
+        let (left, right) = data.split_at(split);
+        let recorder = Recorder::default();
+        let mut front = ParetoFront::new().with_observer(recorder.clone());
+        front.extend(left.iter().cloned());
+
+        // the elements of the merged front are reported to the observer of `front` when they enter it
+        let mut other = ParetoFront::new().with_observer(Recorder::default());
+        other.extend(right.iter().cloned());
+        let nb_submitted = left.len() + other.len();
+        front.merge(other);
+
+        recorder.check(front.as_slice(), nb_submitted);
+    }
+}
+
+/// checks that all thread-local fronts report to the observer
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn observe_concurrent()
+{
+    use pareto_front::ConcurrentParetoFront;
+    use rayon::prelude::*;
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let recorder = Recorder::default();
+    let front = ConcurrentParetoFront::new().with_observer(recorder.clone());
+    data.par_iter().for_each(|x| {
+                       front.push(*x);
+                   });
+    let front = front.into_sequential();
+
+    recorder.check(front.as_slice(), data.len());
+}
@@ -0,0 +1,41 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{OrderedParetoFront, ParetoFront};
+
+/// checks that the ordered front contains the same elements as the default front, in insertion order
+#[test]
+fn insertion_order()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(2000, seed);
+
+    let front: ParetoFront<_> = data.iter().cloned().collect();
+    let ordered_front: OrderedParetoFront<_> = data.iter().cloned().collect();
+
+    // same elements
+    let mut elements: Vec<_> = front.into();
+    elements.sort();
+    let mut ordered_elements = ordered_front.as_slice().to_vec();
+    ordered_elements.sort();
+    assert_eq!(elements, ordered_elements);
+
+    // in the order in which they appear in the data
+    let positions: Vec<_> = ordered_front.iter().map(|x| data.iter().position(|y| x == y).unwrap()).collect();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]));
+}
+
+/// checks that merging appends the non-dominated elements of the other front after the elements of the front
+#[test]
+fn merge_order()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(2000, seed);
+    let (left, right) = data.split_at(1000);
+
+    let mut front: OrderedParetoFront<_> = left.iter().cloned().collect();
+    front.merge(right.iter().cloned().collect());
+    let expected: OrderedParetoFront<_> = data.iter().cloned().collect();
+    assert_eq!(front.as_slice(), expected.as_slice());
+}
@@ -1,8 +1,9 @@
 use rand::{Rng, SeedableRng, rngs::StdRng};
-use pareto_front::Dominate;
+use pareto_front::{Dominate, Objectives};
 
 /// type of the elemnts to be inserted in the front
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ParetoElement
 {
     pub cost: usize,
@@ -19,6 +20,25 @@ impl Dominate for ParetoElement
     }
 }
 
+/// implement the `Objectives` trait, negating the objectives that are maximized
+impl Objectives for ParetoElement
+{
+    fn nb_objectives(&self) -> usize
+    {
+        3
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        match index
+        {
+            0 => self.cost as f64,
+            1 => -(self.quality as f64),
+            _ => -(self.score as f64)
+        }
+    }
+}
+
 impl ParetoElement
 {
     /// creates a fully random element using the given random number generator
@@ -62,8 +62,6 @@ fn push_associativity()
     data.iter().for_each(|x| {
                    seq_front.push(*x);
                });
-    let mut seq_front: Vec<_> = seq_front.into();
-    seq_front.sort();
 
     // sequential front with different insertion order
     data.sort();
@@ -71,10 +69,33 @@ fn push_associativity()
     data.iter().for_each(|x| {
                    sort_front.push(*x);
                });
-    let mut sort_front: Vec<_> = sort_front.into();
-    sort_front.sort();
 
-    // check for equality between both front
-    assert_eq!(seq_front.len(), sort_front.len());
-    assert!(seq_front.eq(&sort_front));
+    // check for equality between both front, independently of the order of their elements
+    assert_eq!(seq_front, sort_front);
+}
+
+/// merges many fronts as a tree and checks the result against a front built from all elements
+#[test]
+fn merge_all()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential front
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // an odd number of fronts, to check that the last front of a round is kept
+    let fronts: Vec<ParetoFront<_>> = data.chunks(77).map(|chunk| chunk.iter().cloned().collect()).collect();
+    let merged_front = ParetoFront::merge_all(fronts.iter().cloned()).unwrap();
+    assert_eq!(seq_front, merged_front);
+
+    #[cfg(feature = "pareto_front_rayon")]
+    {
+        let par_merged_front = ParetoFront::par_merge_all(fronts).unwrap();
+        assert_eq!(seq_front, par_merged_front);
+    }
+
+    // no fronts to merge
+    assert!(ParetoFront::<ParetoElement>::merge_all(Vec::new()).is_none());
 }
@@ -0,0 +1,103 @@
+#![cfg(feature = "pareto_front_concurrent")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{ParetoFront, ParetoFrontActor};
+use std::sync::mpsc::{SendError, TrySendError};
+
+/// sends 1000 elements to an actor from several threads, as single elements and as batches
+/// check the result to ensure it is the same as a sequential front
+#[test]
+fn push_actor()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential front
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // actor, fed by four threads
+    let actor = ParetoFrontActor::with_front(ParetoFront::new(), 16);
+    std::thread::scope(|scope| {
+        for (i, chunk) in data.chunks(250).enumerate()
+        {
+            let handle = actor.handle();
+            scope.spawn(move || {
+                     if i % 2 == 0
+                     {
+                         chunk.iter().for_each(|x| handle.push(*x).unwrap());
+                     }
+                     else
+                     {
+                         chunk.chunks(50).for_each(|batch| handle.push_batch(batch.to_vec()).unwrap());
+                     }
+                 });
+        }
+    });
+
+    // all elements have been sent, the snapshot sees all of them
+    assert_eq!(actor.snapshot().unwrap(), seq_front);
+    assert_eq!(actor.shutdown(), seq_front);
+}
+
+/// checks that handles give the elements back once the actor has been shut down
+#[test]
+fn push_after_shutdown()
+{
+    let element = ParetoElement { cost: 10, quality: 5, score: 0 };
+
+    let actor = ParetoFrontActor::new();
+    let handle = actor.handle();
+    handle.push(element).unwrap();
+    let front = actor.shutdown();
+    assert_eq!(front.as_slice(), &[element]);
+
+    assert_eq!(handle.push(element), Err(SendError(element)));
+    assert_eq!(handle.try_push(element), Err(TrySendError::Disconnected(element)));
+    assert_eq!(handle.push_batch(vec![element]), Err(SendError(vec![element])));
+    assert!(handle.snapshot().is_none());
+}
+
+/// shuts the actor down from a handle while other threads are pushing
+/// check that every element sent successfully made it into the front
+#[test]
+fn shutdown_from_handle()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // actor, fed by four threads, one of which shuts it down halfway
+    let actor = ParetoFrontActor::with_front(ParetoFront::new(), 4);
+    let sent: Vec<ParetoElement> = std::thread::scope(|scope| {
+        let threads: Vec<_> = data.chunks(250)
+                                  .enumerate()
+                                  .map(|(i, chunk)| {
+                                      let handle = actor.handle();
+                                      scope.spawn(move || {
+                                               let mut sent = Vec::new();
+                                               for (j, x) in chunk.iter().enumerate()
+                                               {
+                                                   if (i == 0) && (j == 125)
+                                                   {
+                                                       handle.shutdown().unwrap();
+                                                   }
+                                                   if handle.push(*x).is_ok()
+                                                   {
+                                                       sent.push(*x);
+                                                   }
+                                               }
+                                               sent
+                                           })
+                                  })
+                                  .collect();
+        threads.into_iter().flat_map(|thread| thread.join().unwrap()).collect()
+    });
+
+    // later operations fail, the front contains all elements that were sent
+    let handle = actor.handle();
+    assert_eq!(handle.push(data[0]), Err(SendError(data[0])));
+    assert_eq!(handle.shutdown(), Err(SendError(())));
+    let seq_front: ParetoFront<_> = sent.into_iter().collect();
+    assert_eq!(actor.shutdown(), seq_front);
+}
@@ -0,0 +1,75 @@
+#![cfg(feature = "pareto_front_rayon")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::ParetoFront;
+use rayon::prelude::*;
+
+/// collects a parallel iterator into a front and checks the result against a sequential front
+#[test]
+fn par_collect()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+    let par_front: ParetoFront<_> = data.par_iter().cloned().collect();
+    assert_eq!(seq_front, par_front);
+}
+
+/// extends a non-empty front with a parallel iterator
+#[test]
+fn par_extend()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let (left, right) = data.split_at(500);
+
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+    let mut par_front: ParetoFront<_> = left.iter().cloned().collect();
+    par_front.par_extend(right.par_iter().cloned());
+    assert_eq!(seq_front, par_front);
+}
+
+/// iterates on a front in parallel
+#[test]
+fn par_iter()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let front: ParetoFront<_> = data.iter().cloned().collect();
+    let mut elements: Vec<_> = front.par_iter().cloned().collect();
+    elements.sort();
+    let mut expected = front.as_slice().to_vec();
+    expected.sort();
+    assert_eq!(elements, expected);
+}
+
+/// pushes candidates into a front large enough to be scanned in parallel
+/// checks that `par_push` and `push` agree
+#[test]
+fn par_push()
+{
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    // a front of 20000 elements that do not dominate each other
+    let front: ParetoFront<_> =
+        (0..20000).map(|i| ParetoElement { cost: i, quality: 0, score: i as i64 }).collect();
+    assert_eq!(front.len(), 20000);
+
+    // candidates that are dominated, dominate some elements or are incomparable to the front
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut seq_front = front.clone();
+    let mut par_front = front;
+    for _ in 0..100
+    {
+        let candidate = ParetoElement { cost: rng.random_range(0..25000),
+                                        quality: rng.random_range(0..2),
+                                        score: rng.random_range(0..25000) };
+        assert_eq!(seq_front.push(candidate), par_front.par_push(candidate));
+    }
+    assert_eq!(seq_front, par_front);
+}
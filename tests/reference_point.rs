@@ -0,0 +1,109 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{Dominate, ParetoFront, ReferencePoint, ReferencePointFront};
+
+/// reference point that keeps the elements with a small cost and a decent quality and score
+fn reference_point() -> ReferencePoint
+{
+    ReferencePoint::new(vec![0., -128., 0.], (usize::MAX / 4) as f64)
+}
+
+/// checks that the front focused on a reference point is the part of the full front that is close to it
+#[test]
+fn filter_front()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let reference_point = reference_point();
+
+    // full front, filtered after the fact
+    let full_front: ParetoFront<_> = data.iter().cloned().collect();
+    let mut full_front: Vec<_> = full_front.into_iter().filter(|x| reference_point.contains(x)).collect();
+    full_front.sort();
+
+    // focused front
+    let mut focused_front: ReferencePointFront<ParetoFront<_>> = ReferencePointFront::new(reference_point);
+    for x in data.iter()
+    {
+        let pushed = focused_front.push(*x);
+        assert_eq!(pushed, focused_front.inner().as_slice().contains(x));
+    }
+    let mut focused_front: Vec<_> = focused_front.into_inner().into();
+    focused_front.sort();
+
+    // both approaches should give the same result
+    assert!(!focused_front.is_empty());
+    assert_eq!(full_front, focused_front);
+}
+
+/// checks that updating the reference point removes the elements that are now too far from it
+#[test]
+fn update_reference_point()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // focused front
+    let full_front: ParetoFront<_> = data.iter().cloned().collect();
+    let mut front = ReferencePointFront::from_front(full_front, reference_point());
+    let size_before = front.inner().len();
+
+    // reduces the size of the region of interest
+    let narrower_reference_point = ReferencePoint::new(vec![0., -128., 0.], (usize::MAX / 8) as f64);
+    front.set_reference_point(narrower_reference_point.clone());
+    assert!(front.inner().len() < size_before);
+    assert!(front.inner().iter().all(|x| narrower_reference_point.contains(x)));
+
+    // the front is still a valid Pareto front
+    let elements = front.inner().as_slice();
+    assert!(elements.iter().all(|x| elements.iter().all(|y| !y.dominate(x))));
+}
+
+/// checks that a focused concurrent front gives the same result as a focused sequential front
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn filter_concurrent_front()
+{
+    use pareto_front::ConcurrentParetoFront;
+    use rayon::prelude::*;
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential focused front
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+    let seq_front = ReferencePointFront::from_front(seq_front, reference_point());
+    let mut seq_front: Vec<_> = seq_front.into_inner().into();
+    seq_front.sort();
+
+    // concurrent focused front
+    let conc_front: ReferencePointFront<ConcurrentParetoFront<_>> =
+        ReferencePointFront::new(reference_point());
+    data.par_iter().for_each(|x| {
+                       conc_front.push(*x);
+                   });
+    let mut conc_front: Vec<_> = conc_front.into_inner().into();
+    conc_front.sort();
+
+    // checks for equality
+    assert_eq!(seq_front, conc_front);
+}
+
+/// checks that negative weights, which would break the consistency with dominance, are refused
+#[test]
+#[should_panic]
+fn negative_weight()
+{
+    let _ = reference_point().with_weights(vec![1., -1., 1.]);
+}
+
+/// checks that NaN thresholds are refused
+#[test]
+#[should_panic]
+fn nan_threshold()
+{
+    let _ = ReferencePoint::new(vec![0., 0.], f64::NAN);
+}
@@ -0,0 +1,115 @@
+use pareto_front::relaxed_dominance::{AlphaDominance, FuzzyDominance, GridDominance, KDominance};
+use pareto_front::{Dominance, Dominate, NaturalDominance, Objectives, ParetoFront};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+/// number of objectives of the test elements
+const NB_OBJECTIVES: usize = 10;
+
+/// element with a large number of objectives, all minimized
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ManyObjectivesElement
+{
+    coordinates: [f64; NB_OBJECTIVES]
+}
+
+impl Dominate for ManyObjectivesElement
+{
+    fn dominate(&self, x: &Self) -> bool
+    {
+        self.coordinates.iter().zip(x.coordinates.iter()).all(|(sc, xc)| sc <= xc) && (self != x)
+    }
+}
+
+impl Objectives for ManyObjectivesElement
+{
+    fn nb_objectives(&self) -> usize
+    {
+        NB_OBJECTIVES
+    }
+
+    fn objective(&self, index: usize) -> f64
+    {
+        self.coordinates[index]
+    }
+}
+
+/// creates the given number of elements, uniformly sampled in the unit hypercube
+fn sample_n(n: usize, seed: u64) -> Vec<ManyObjectivesElement>
+{
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..n).map(|_| ManyObjectivesElement { coordinates: std::array::from_fn(|_| rng.random()) }).collect()
+}
+
+/// builds a front with the given dominance relation
+/// and checks that none of its elements dominates another one
+fn build_front<D: Dominance<ManyObjectivesElement>>(data: &[ManyObjectivesElement],
+                                                    dominance: D)
+                                                    -> ParetoFront<ManyObjectivesElement, D>
+{
+    let mut front = ParetoFront::with_comparator(dominance);
+    front.extend(data.iter().cloned());
+    let elements = front.as_slice();
+    let dominance = front.comparator();
+    assert!(elements.iter().all(|x| elements.iter().all(|y| !dominance.dominate(y, x))));
+    front
+}
+
+/// checks that all relaxed relations are implied by Pareto dominance
+#[test]
+fn implied_by_pareto_dominance()
+{
+    let data = sample_n(1000, 42);
+    let k_dominance = KDominance::new(0.5);
+    let alpha_dominance = AlphaDominance::new(0.1);
+    let grid_dominance = GridDominance::new(vec![0.; NB_OBJECTIVES], vec![1.; NB_OBJECTIVES], 4);
+    let fuzzy_dominance = FuzzyDominance::new(0.1, 0.5);
+    for (x, y) in data.iter().zip(data.iter().skip(1))
+    {
+        // makes sure `x` dominates `y`
+        let mut x = *x;
+        x.coordinates.iter_mut().zip(y.coordinates.iter()).for_each(|(xc, yc)| *xc = xc.min(*yc));
+        if x.dominate(y)
+        {
+            assert!(k_dominance.dominate(&x, y));
+            assert!(alpha_dominance.dominate(&x, y));
+            assert!(grid_dominance.dominate(&x, y));
+            assert!(fuzzy_dominance.dominate(&x, y));
+        }
+    }
+}
+
+/// checks that relaxed relations lead to smaller fronts in a many-objectives setting
+#[test]
+fn smaller_fronts()
+{
+    let data = sample_n(2000, 42);
+    let pareto_size = build_front(&data, NaturalDominance).len();
+    let k_size = build_front(&data, KDominance::new(0.5)).len();
+    let alpha_size = build_front(&data, AlphaDominance::new(0.1)).len();
+    let grid_size =
+        build_front(&data, GridDominance::new(vec![0.; NB_OBJECTIVES], vec![1.; NB_OBJECTIVES], 4)).len();
+    let fuzzy_size = build_front(&data, FuzzyDominance::new(0.1, 0.5)).len();
+    assert!(k_size < pareto_size);
+    assert!(alpha_size < pareto_size);
+    assert!(grid_size < pareto_size);
+    assert!(fuzzy_size < pareto_size);
+}
+
+/// checks that k-dominance with `k=0` is Pareto dominance
+#[test]
+fn k_dominance_generalizes_pareto_dominance()
+{
+    let data = sample_n(1000, 42);
+    let pareto_front = build_front(&data, NaturalDominance);
+    let k_front = build_front(&data, KDominance::new(0.));
+    assert_eq!(pareto_front.len(), k_front.len());
+    assert!(k_front.iter().all(|x| pareto_front.as_slice().contains(x)));
+}
+
+/// checks that a grid with an empty range along an objective, which would have cells of size zero, is refused
+#[test]
+#[should_panic]
+fn grid_with_empty_range()
+{
+    let _ = GridDominance::new(vec![0., 0.], vec![1., 0.], 4);
+}
@@ -0,0 +1,72 @@
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{ParetoFront, ReorderStrategy};
+
+/// checks that all strategies produce the same front, with identifiers still pointing to the right elements
+#[test]
+fn same_front()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(2000, seed);
+
+    let reference: ParetoFront<_> = data.iter().cloned().collect();
+    for reorder_strategy in [ReorderStrategy::None,
+                             ReorderStrategy::SwapWithPrevious,
+                             ReorderStrategy::MoveToFront,
+                             ReorderStrategy::CountBased]
+    {
+        let mut front = ParetoFront::new().with_reorder_strategy(reorder_strategy);
+        let ids: Vec<_> = data.iter().map(|x| (front.push_with_id(*x), *x)).collect();
+        assert_eq!(front, reference);
+
+        for (id, x) in ids.iter().filter_map(|(id, x)| id.map(|id| (id, x)))
+        {
+            if let Some(element) = front.get(id)
+            {
+                assert_eq!(element, x);
+            }
+        }
+    }
+}
+
+/// checks that the count based strategy keeps the element that dominated the most candidates first
+#[test]
+fn count_based()
+{
+    let mut front = ParetoFront::new().with_reorder_strategy(ReorderStrategy::CountBased);
+    front.push(ParetoElement { cost: 10, quality: 5, score: 0 });
+    front.push(ParetoElement { cost: 20, quality: 10, score: 0 });
+
+    // two candidates dominated by the second element, one by the first
+    front.push(ParetoElement { cost: 30, quality: 9, score: 0 });
+    front.push(ParetoElement { cost: 30, quality: 8, score: 0 });
+    front.push(ParetoElement { cost: 15, quality: 4, score: 0 });
+    assert_eq!(front.as_slice()[0].cost, 20);
+
+    // the first element catches up and overtakes the second one
+    front.push(ParetoElement { cost: 15, quality: 3, score: 0 });
+    assert_eq!(front.as_slice()[0].cost, 20);
+    front.push(ParetoElement { cost: 15, quality: 2, score: 0 });
+    assert_eq!(front.as_slice()[0].cost, 10);
+}
+
+/// checks that the count based strategy keeps the elements sorted when an element is evicted
+#[test]
+fn count_based_eviction()
+{
+    let mut front = ParetoFront::new().with_reorder_strategy(ReorderStrategy::CountBased);
+    front.push(ParetoElement { cost: 10, quality: 5, score: 0 });
+    front.push(ParetoElement { cost: 20, quality: 10, score: 0 });
+    front.push(ParetoElement { cost: 30, quality: 15, score: 0 });
+
+    // two candidates dominated by the first element, one by the second
+    front.push(ParetoElement { cost: 11, quality: 4, score: 0 });
+    front.push(ParetoElement { cost: 12, quality: 4, score: 0 });
+    front.push(ParetoElement { cost: 25, quality: 9, score: 0 });
+
+    // evicts the first element, the others keep their order instead of the last one taking its place
+    front.push(ParetoElement { cost: 5, quality: 6, score: 0 });
+    let costs: Vec<_> = front.iter().map(|x| x.cost).collect();
+    assert_eq!(costs, vec![20, 30, 5]);
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "pareto_front_serde")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::ParetoFront;
+
+/// serializes a front to JSON and back
+#[test]
+fn round_trip()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let front: ParetoFront<_> = data.iter().cloned().collect();
+
+    let json = serde_json::to_string(&front).unwrap();
+    let deserialized_front: ParetoFront<ParetoElement> = serde_json::from_str(&json).unwrap();
+    assert_eq!(front, deserialized_front);
+}
+
+/// serializes a concurrent front, filled from several threads, to JSON and back
+/// the concurrent front is serialized as the equivalent sequential front
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn concurrent_round_trip()
+{
+    use pareto_front::ConcurrentParetoFront;
+    use std::sync::Barrier;
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // one thread-local front per chunk of data
+    // the barrier keeps all threads alive so that they do not share a thread-local front
+    let conc_front = ConcurrentParetoFront::new();
+    let barrier = Barrier::new(4);
+    std::thread::scope(|scope| {
+        for chunk in data.chunks(250)
+        {
+            scope.spawn(|| {
+                     chunk.iter().for_each(|x| {
+                                     conc_front.push(*x);
+                                 });
+                     barrier.wait();
+                 });
+        }
+    });
+
+    // serialized as a sequential front, without consuming the concurrent front
+    let json = serde_json::to_string(&conc_front).unwrap();
+    let deserialized_seq_front: ParetoFront<ParetoElement> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized_seq_front, seq_front);
+
+    // deserialized back into a concurrent front
+    let deserialized_front: ConcurrentParetoFront<ParetoElement> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized_front.into_sequential(), seq_front);
+    assert_eq!(conc_front.into_sequential(), seq_front);
+}
+
+/// elements with the first one dominating the third one
+const CORRUPTED_FRONT: &str = r#"{"front": [{"cost": 1, "quality": 5, "score": 5},
+                                         {"cost": 5, "quality": 9, "score": 0},
+                                         {"cost": 2, "quality": 4, "score": 1}]}"#;
+
+/// checks that deserialization fails, listing the dominated elements, when the front is invalid
+#[test]
+fn strict()
+{
+    let error = serde_json::from_str::<ParetoFront<ParetoElement>>(CORRUPTED_FRONT).unwrap_err();
+    assert!(error.to_string().contains("[2]"), "{error}");
+
+    // without the dominated element, the front is valid
+    let valid_front =
+        r#"{"front": [{"cost": 1, "quality": 5, "score": 5}, {"cost": 5, "quality": 9, "score": 0}]}"#;
+    let front: ParetoFront<ParetoElement> = serde_json::from_str(valid_front).unwrap();
+    assert_eq!(front.len(), 2);
+}
+
+/// checks that the repair mode drops the dominated elements
+#[test]
+fn repair()
+{
+    #[derive(serde::Deserialize)]
+    struct Checkpoint
+    {
+        #[serde(deserialize_with = "ParetoFront::deserialize_repair")]
+        front: ParetoFront<ParetoElement>
+    }
+
+    let json = format!(r#"{{"front": {CORRUPTED_FRONT}}}"#);
+    let checkpoint: Checkpoint = serde_json::from_str(&json).unwrap();
+    let mut elements: Vec<_> = checkpoint.front.into();
+    elements.sort();
+    assert_eq!(elements, vec![ParetoElement { cost: 1, quality: 5, score: 5 }, ParetoElement { cost: 5,
+                                                                                               quality:
+                                                                                                   9,
+                                                                                               score: 0 }]);
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "pareto_front_concurrent")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{ParetoFront, SharedParetoFront};
+use rayon::prelude::*;
+
+/// adds 1000 elements to a ParetoFront and a SharedParetoFront
+/// check the result to ensure they are the same
+#[test]
+fn push_shared()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    // sequential front
+    let seq_front: ParetoFront<_> = data.iter().cloned().collect();
+
+    // shared front
+    let shared_front = SharedParetoFront::new();
+    data.par_iter().for_each(|x| {
+                       shared_front.push(*x);
+                   });
+
+    // checks for equality with shared front
+    assert_eq!(shared_front.snapshot(), seq_front);
+    assert_eq!(shared_front.into_sequential(), seq_front);
+}
+
+/// checks that elements rejected by `push` are dominated by the final front
+/// whichever thread inserted the dominating element
+#[test]
+fn definitive_push()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let shared_front = SharedParetoFront::new();
+    let rejected: Vec<_> = data.par_iter().filter(|x| !shared_front.push(**x)).cloned().collect();
+
+    let front = shared_front.into_sequential();
+    assert!(!rejected.is_empty());
+    assert!(rejected.iter().all(|x| front.dominate(x)));
+}
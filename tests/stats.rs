@@ -0,0 +1,119 @@
+#![cfg(feature = "pareto_front_stats")]
+mod pareto_element;
+use pareto_element::ParetoElement;
+use pareto_front::{Dominance, Dominate, FrontStats, ParetoFront};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// natural dominance that counts the number of times it is called
+/// shares its counter between clones so that it can be used with concurrent fronts
+#[derive(Clone, Default)]
+struct CountingDominance
+{
+    count: Arc<AtomicU64>
+}
+
+impl Dominance<ParetoElement> for CountingDominance
+{
+    fn dominate(&self, x: &ParetoElement, y: &ParetoElement) -> bool
+    {
+        self.count.fetch_add(1, Ordering::Relaxed);
+        x.dominate(y)
+    }
+}
+
+/// checks the counters against the number of calls to the comparator and the content of the front
+#[test]
+fn push_stats()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let dominance = CountingDominance::default();
+    let mut front = ParetoFront::with_comparator(dominance.clone());
+    let accepted = data.iter().filter(|x| front.push(**x)).count() as u64;
+
+    let stats = *front.stats();
+    assert_eq!(stats.comparisons, dominance.count.load(Ordering::Relaxed));
+    assert_eq!(stats.early_rejections, data.len() as u64 - accepted);
+    assert_eq!(stats.evictions, accepted - front.len() as u64);
+    assert_eq!(stats.merges, 0);
+
+    // `dominate` is a query, it is not counted
+    front.dominate(&data[0]);
+    assert_eq!(front.stats().comparisons, stats.comparisons);
+
+    // explicitly removed elements are counted as evicted
+    let (id, _) = front.iter_with_ids().next().unwrap();
+    front.remove(id).unwrap();
+    assert_eq!(front.stats().evictions, stats.evictions + 1);
+
+    front.reset_stats();
+    assert_eq!(*front.stats(), FrontStats::default());
+}
+
+/// checks that merges are counted and that elements of either front can be evicted
+#[test]
+fn merge_stats()
+{
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+    let (left, right) = data.split_at(500);
+
+    let mut front: ParetoFront<_> = left.iter().cloned().collect();
+    let other: ParetoFront<_> = right.iter().cloned().collect();
+    let total_len = (front.len() + other.len()) as u64;
+    front.reset_stats();
+
+    front.merge(other);
+    let stats = *front.stats();
+    assert_eq!(stats.merges, 1);
+    assert_eq!(stats.early_rejections + stats.evictions, total_len - front.len() as u64);
+}
+
+/// checks that the counters of all thread-local fronts are summed
+#[test]
+#[cfg(feature = "pareto_front_concurrent")]
+fn concurrent_stats()
+{
+    use pareto_front::ConcurrentParetoFront;
+    use rayon::prelude::*;
+
+    // data to be put in the front
+    let seed = 42;
+    let data = ParetoElement::sample_n(1000, seed);
+
+    let dominance = CountingDominance::default();
+    let front = ConcurrentParetoFront::with_comparator(dominance.clone());
+    let accepted = data.par_iter().filter(|x| front.push(**x)).count() as u64;
+
+    let stats = front.stats();
+    assert_eq!(stats.comparisons, dominance.count.load(Ordering::Relaxed));
+    assert_eq!(stats.early_rejections, data.len() as u64 - accepted);
+
+    // merging the thread-local fronts keeps the counters
+    let front = front.into_sequential();
+    assert!(front.stats().comparisons >= stats.comparisons);
+    assert_eq!(front.stats().comparisons, dominance.count.load(Ordering::Relaxed));
+
+    let front: ConcurrentParetoFront<_, _> = front.into();
+    front.reset_stats();
+    assert_eq!(front.stats(), FrontStats::default());
+
+    // counters can be read while other threads are pushing
+    let (data1, data2) = data.split_at(data.len() / 2);
+    std::thread::scope(|scope| {
+        scope.spawn(|| data1.iter().for_each(|x| _ = front.push(*x)));
+        scope.spawn(|| data2.iter().for_each(|x| _ = front.push(*x)));
+        let mut previous_comparisons = 0;
+        for _ in 0..100
+        {
+            let comparisons = front.stats().comparisons;
+            assert!(comparisons >= previous_comparisons);
+            previous_comparisons = comparisons;
+        }
+    });
+    assert!(front.stats().comparisons > 0);
+}